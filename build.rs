@@ -0,0 +1,79 @@
+// Build script: informasi build untuk metadata environment di report
+//
+// Semua nilai diteruskan sebagai env var compile-time (`env!`) ke
+// `environment.rs`: versi rustc, target, profile/opt-level, target features
+// dan versi dependency langsung (dari Cargo.toml + Cargo.lock).
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|version| version.trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let var = |name: &str| env::var(name).unwrap_or_else(|_| "unknown".to_string());
+    emit("CRYPTO_BENCH_RUSTC_VERSION", &rustc_version);
+    emit("CRYPTO_BENCH_TARGET", &var("TARGET"));
+    emit("CRYPTO_BENCH_PROFILE", &var("PROFILE"));
+    emit("CRYPTO_BENCH_OPT_LEVEL", &var("OPT_LEVEL"));
+    emit("CRYPTO_BENCH_TARGET_FEATURES", &env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default());
+    emit("CRYPTO_BENCH_DEPENDENCIES", &dependency_versions(Path::new(&manifest_dir)));
+}
+
+fn emit(key: &str, value: &str) {
+    println!("cargo:rustc-env={}={}", key, value);
+}
+
+/// "nama=versi,..." untuk setiap dependency langsung yang ada di Cargo.lock.
+/// Crate dengan beberapa versi di lock file ditulis "nama=v1/v2".
+fn dependency_versions(manifest_dir: &Path) -> String {
+    let manifest = fs::read_to_string(manifest_dir.join("Cargo.toml")).unwrap_or_default();
+    let lock = fs::read_to_string(manifest_dir.join("Cargo.lock")).unwrap_or_default();
+
+    let mut direct: Vec<String> = Vec::new();
+    let mut in_dependencies = false;
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_dependencies = line == "[dependencies]" || line.ends_with(".dependencies]");
+            continue;
+        }
+        if let Some((name, _)) = line.split_once('=').filter(|_| in_dependencies) {
+            direct.push(name.trim().to_string());
+        }
+    }
+
+    let mut locked: Vec<(String, String)> = Vec::new();
+    let mut name: Option<String> = None;
+    for line in lock.lines().map(str::trim) {
+        let quoted = |prefix: &str| line.strip_prefix(prefix).map(|v| v.trim_matches('"').to_string());
+        if let Some(n) = quoted("name = ") {
+            name = Some(n);
+        } else if let (Some(version), Some(n)) = (quoted("version = "), name.take()) {
+            locked.push((n, version));
+        }
+    }
+
+    direct.sort();
+    direct.dedup();
+    direct
+        .iter()
+        .filter_map(|dep| {
+            let versions: Vec<&str> =
+                locked.iter().filter(|(n, _)| n == dep).map(|(_, v)| v.as_str()).collect();
+            (!versions.is_empty()).then(|| format!("{}={}", dep, versions.join("/")))
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,108 @@
+// Counting global allocator untuk tracking alokasi per operasi
+//
+// Opt-in: binary harus memasang allocator ini sebagai `#[global_allocator]`
+// (lihat feature `alloc-tracking` di main.rs). Counter bersifat global untuk
+// seluruh proses, jadi pengukuran hanya akurat jika tidak ada thread lain
+// yang sedang mengalokasikan memory.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+static TRACKING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wrapper di atas `System` yang menghitung alokasi selama tracking aktif
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[inline]
+fn record_alloc(size: usize) {
+    if !TRACKING.load(Ordering::Relaxed) {
+        return;
+    }
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+#[inline]
+fn record_dealloc(size: usize) {
+    if !TRACKING.load(Ordering::Relaxed) {
+        return;
+    }
+    // Memory yang dialokasikan sebelum tracking aktif tidak boleh membuat underflow
+    let _ = LIVE_BYTES.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+        Some(live.saturating_sub(size))
+    });
+}
+
+/// Total alokasi selama satu tracking window
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllocationDelta {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    /// Puncak live bytes di atas baseline saat window dimulai
+    pub peak_live_bytes: usize,
+}
+
+/// Jalankan `f` dengan tracking aktif dan kembalikan alokasi yang terjadi
+pub fn track<F, R>(f: F) -> (R, AllocationDelta)
+where
+    F: FnOnce() -> R,
+{
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES_ALLOCATED.store(0, Ordering::Relaxed);
+    LIVE_BYTES.store(0, Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(0, Ordering::Relaxed);
+
+    TRACKING.store(true, Ordering::SeqCst);
+    let result = f();
+    TRACKING.store(false, Ordering::SeqCst);
+
+    let delta = AllocationDelta {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed),
+        peak_live_bytes: PEAK_LIVE_BYTES.load(Ordering::Relaxed),
+    };
+    (result, delta)
+}
+
+/// `true` jika `CountingAllocator` terpasang sebagai global allocator
+pub fn is_installed() -> bool {
+    let (_, delta) = track(|| std::hint::black_box(Box::new(0u64)));
+    delta.allocations > 0
+}
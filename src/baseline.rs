@@ -0,0 +1,252 @@
+// Baseline bernama di disk dan deteksi regresi
+//
+// Baseline adalah BenchmarkReport biasa yang disimpan sebagai `<dir>/<nama>.json`,
+// jadi juga bisa dipakai langsung oleh `compare`. Run berikutnya dibandingkan
+// dengan `compare::compare_reports`; metric yang "regressed" dan melebihi
+// threshold (default atau per metric) dihitung sebagai regresi. Metric yang
+// ada di baseline tapi hilang dari run sekarang juga menggagalkan check.
+use std::io;
+use std::path::{Path, PathBuf};
+use serde::{Deserialize, Serialize};
+use crate::compare::{ComparisonReport, MetricDelta, Verdict};
+use crate::constants::DEFAULT_REGRESSION_THRESHOLD_PERCENT;
+use crate::models::BenchmarkReport;
+use crate::schema;
+
+/// Direktori berisi baseline, satu file JSON per nama
+#[derive(Debug, Clone)]
+pub struct BaselineStore {
+    dir: PathBuf,
+}
+
+impl BaselineStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        BaselineStore { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Nama hanya boleh huruf, angka, `.`, `_` dan `-` (tanpa path separator)
+    pub fn path(&self, name: &str) -> io::Result<PathBuf> {
+        let valid = !name.is_empty()
+            && !name.starts_with('.')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-'));
+        if !valid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid baseline name '{}' (use letters, digits, '.', '_' or '-')", name),
+            ));
+        }
+        Ok(self.dir.join(format!("{}.json", name)))
+    }
+
+    pub fn save(&self, name: &str, report: &BenchmarkReport) -> io::Result<PathBuf> {
+        let path = self.path(name)?;
+        std::fs::create_dir_all(&self.dir)?;
+        let json = serde_json::to_string_pretty(report).map_err(io::Error::other)?;
+        std::fs::write(&path, json + "\n")?;
+        Ok(path)
+    }
+
+    pub fn load(&self, name: &str) -> io::Result<BenchmarkReport> {
+        let contents = std::fs::read_to_string(self.path(name)?)?;
+        schema::load_report(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Nama semua baseline, terurut. Direktori yang belum ada = kosong.
+    pub fn list(&self) -> io::Result<Vec<String>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        let mut names = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+                    names.push(stem.to_string());
+                }
+            }
+        }
+        names.sort();
+        Ok(names)
+    }
+}
+
+/// Threshold untuk metric yang cocok dengan `pattern`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricThreshold {
+    pub pattern: String,
+    pub percent: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Thresholds {
+    pub default_percent: f64,
+    pub per_metric: Vec<MetricThreshold>,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds { default_percent: DEFAULT_REGRESSION_THRESHOLD_PERCENT, per_metric: Vec::new() }
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+impl Thresholds {
+    /// Pattern dicocokkan (case-insensitive, tanpa tanda baca) dengan
+    /// "<section> <label>", mis. "rsa-2048 sign" atau "kem: ml-kem". Jika
+    /// beberapa pattern cocok, yang terpanjang (paling spesifik) dipakai.
+    pub fn for_delta(&self, delta: &MetricDelta) -> f64 {
+        let target = normalize(&format!("{} {}", delta.section, delta.label()));
+        self.per_metric
+            .iter()
+            .map(|t| (normalize(&t.pattern), t.percent))
+            .filter(|(pattern, _)| target.contains(pattern.as_str()))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map_or(self.default_percent, |(_, percent)| percent)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Regression {
+    pub section: String,
+    pub metric: String,
+    pub percent_change: f64,
+    pub threshold_percent: f64,
+    pub p_value: f64,
+}
+
+/// Metric yang regressed secara signifikan dan melewati threshold-nya
+pub fn regressions(comparison: &ComparisonReport, thresholds: &Thresholds) -> Vec<Regression> {
+    comparison
+        .deltas
+        .iter()
+        .filter(|delta| delta.verdict == Verdict::Regressed)
+        .filter_map(|delta| {
+            let threshold = thresholds.for_delta(delta);
+            (delta.percent_change > threshold).then(|| Regression {
+                section: delta.section.clone(),
+                metric: delta.label(),
+                percent_change: delta.percent_change,
+                threshold_percent: threshold,
+                p_value: delta.test.p_value,
+            })
+        })
+        .collect()
+}
+
+/// Hasil `baseline check`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaselineCheck {
+    pub baseline: String,
+    pub thresholds: Thresholds,
+    pub comparison: ComparisonReport,
+    pub regressions: Vec<Regression>,
+    /// Metric di baseline yang tidak ada lagi di run sekarang
+    pub missing: Vec<String>,
+}
+
+impl BaselineCheck {
+    pub fn new(baseline: String, comparison: ComparisonReport, thresholds: Thresholds) -> Self {
+        BaselineCheck {
+            baseline,
+            regressions: regressions(&comparison, &thresholds),
+            missing: comparison.only_in_baseline.clone(),
+            thresholds,
+            comparison,
+        }
+    }
+
+    /// Operasi yang hilang ikut menggagalkan check, supaya gate tidak lolos
+    /// hanya karena metric-nya tidak diukur lagi
+    pub fn passed(&self) -> bool {
+        self.regressions.is_empty() && self.missing.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::compare::WelchTest;
+
+    fn delta(section: &str, name: &str, operation: &str) -> MetricDelta {
+        MetricDelta {
+            section: section.to_string(),
+            name: name.to_string(),
+            operation: operation.to_string(),
+            implementation: None,
+            message_size: None,
+            baseline_mean_micros: 100.0,
+            candidate_mean_micros: 112.0,
+            delta_micros: 12.0,
+            percent_change: 12.0,
+            test: WelchTest { t_statistic: 10.0, degrees_of_freedom: 100.0, p_value: 0.0 },
+            verdict: Verdict::Regressed,
+        }
+    }
+
+    #[test]
+    fn test_most_specific_threshold_wins() {
+        let thresholds = Thresholds {
+            default_percent: 10.0,
+            per_metric: vec![
+                MetricThreshold { pattern: "rsa".to_string(), percent: 20.0 },
+                MetricThreshold { pattern: "RSA-2048 Sign".to_string(), percent: 5.0 },
+            ],
+        };
+        assert_eq!(thresholds.for_delta(&delta("signatures", "RSA-2048", "Sign")), 5.0);
+        assert_eq!(thresholds.for_delta(&delta("signatures", "RSA-2048", "Verify")), 20.0);
+        assert_eq!(thresholds.for_delta(&delta("signatures", "Ed25519", "Sign")), 10.0);
+
+        let comparison = ComparisonReport {
+            significance_level: 0.05,
+            min_change_percent: 1.0,
+            deltas: vec![delta("signatures", "RSA-2048", "Sign"), delta("signatures", "RSA-2048", "Verify")],
+            only_in_baseline: Vec::new(),
+            only_in_candidate: Vec::new(),
+        };
+        let found = regressions(&comparison, &thresholds);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].metric, "RSA-2048 Sign");
+    }
+
+    #[test]
+    fn test_missing_metric_fails_check() {
+        let comparison = ComparisonReport {
+            significance_level: 0.05,
+            min_change_percent: 1.0,
+            deltas: Vec::new(),
+            only_in_baseline: vec!["signatures: Ed25519 Sign".to_string()],
+            only_in_candidate: Vec::new(),
+        };
+        let check = BaselineCheck::new("main".to_string(), comparison, Thresholds::default());
+        assert!(check.regressions.is_empty());
+        assert_eq!(check.missing, vec!["signatures: Ed25519 Sign".to_string()]);
+        assert!(!check.passed());
+
+        let complete = ComparisonReport { only_in_baseline: Vec::new(), ..check.comparison.clone() };
+        assert!(BaselineCheck::new("main".to_string(), complete, Thresholds::default()).passed());
+    }
+
+    #[test]
+    fn test_store_round_trip_and_name_validation() {
+        let dir = std::env::temp_dir().join(format!("crypto_benchmark_baselines_{}", std::process::id()));
+        let store = BaselineStore::new(&dir);
+        assert!(store.list().unwrap().is_empty());
+        assert!(store.path("../escape").is_err());
+
+        let report = BenchmarkReport { keygen_time_secs: 1.5, ..Default::default() };
+        store.save("main", &report).unwrap();
+        assert_eq!(store.list().unwrap(), vec!["main".to_string()]);
+        assert_eq!(store.load("main").unwrap().keygen_time_secs, 1.5);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,222 @@
+// Cold-cache vs warm-cache measurement
+//
+// Warm: `benchmark_operation_with` biasa (warmup, satu set input, batched).
+// Cold: `benchmark_operation_cold`, cache dikosongkan sebelum setiap sample
+// dan input dirotasi di antara banyak `KeySet`. Verifikasi di dunia nyata
+// sering terjadi pada data yang belum ada di cache.
+use std::hint::black_box;
+use ed25519_dalek::{Signer, Verifier};
+use rsa::Pss;
+use sha2::{Digest, Sha256};
+use x25519_dalek::EphemeralSecret;
+use p256::ecdh::EphemeralSecret as P256EphemeralSecret;
+use pqcrypto_mldsa::mldsa44;
+use pqcrypto_falcon::falcon512;
+use pqcrypto_mlkem::mlkem512;
+use rand::rngs::OsRng;
+use crate::keys::{KeyPool, KeySet};
+use crate::measurement::{benchmark_operation_cold, benchmark_operation_with, MeasurementConfig};
+use crate::models::CacheComparison;
+
+const CACHE_LINE_BYTES: usize = 64;
+
+/// Buffer besar yang ditulis per cache line untuk mengusir data lain dari
+/// L1/L2/LLC. Ukurannya harus melebihi last-level cache.
+pub struct CacheEvictor {
+    buffer: Vec<u8>,
+    round: u8,
+}
+
+impl CacheEvictor {
+    pub fn new(bytes: usize) -> Self {
+        CacheEvictor { buffer: vec![0u8; bytes.max(CACHE_LINE_BYTES)], round: 0 }
+    }
+
+    /// Tulis satu byte per cache line; nilai berubah tiap ronde supaya
+    /// store tidak bisa dihilangkan
+    pub fn evict(&mut self) {
+        self.round = self.round.wrapping_add(1);
+        for line in self.buffer.chunks_mut(CACHE_LINE_BYTES) {
+            line[0] = line[0].wrapping_add(self.round);
+        }
+        black_box(&self.buffer);
+    }
+}
+
+/// Ukur satu operasi warm (set pertama) dan cold (rotasi seluruh pool).
+/// `None` jika algoritma tidak lolos filter `config.algorithms`.
+fn compare<F, R>(
+    name: &str,
+    operation: &str,
+    pool: &KeyPool,
+    op: F,
+    iterations: usize,
+    config: &MeasurementConfig,
+) -> Option<CacheComparison>
+where
+    F: Fn(&KeySet) -> R + Sync,
+{
+    if !config.includes(name) {
+        return None;
+    }
+    let warm_set = &pool.sets[0];
+    let warm = benchmark_operation_with(|| op(warm_set), iterations, config)
+        .to_metric(name.to_string(), operation.to_string(), Default::default());
+    let mut cold = benchmark_operation_cold(&pool.sets, &op, iterations, config)
+        .to_metric(name.to_string(), operation.to_string(), Default::default());
+    cold.extra_info.insert("key_sets".to_string(), pool.len().to_string());
+
+    Some(CacheComparison {
+        name: name.to_string(),
+        operation: operation.to_string(),
+        cold_to_warm_ratio: if warm.mean_micros > 0.0 { cold.mean_micros / warm.mean_micros } else { 0.0 },
+        warm,
+        cold,
+    })
+}
+
+/// Warm vs cold untuk setiap signature dan KEM operation
+pub fn benchmark_cache_modes(pool: &KeyPool, iterations: usize, config: &MeasurementConfig) -> Vec<CacheComparison> {
+    [
+        // Ed25519
+        compare("Ed25519", "Sign", pool, |s| s.ed25519_signing.sign(&s.message), iterations, config),
+        compare(
+            "Ed25519",
+            "Verify",
+            pool,
+            |s| s.ed25519_verifying.verify(&s.message, &s.ed25519_signature).expect("Verification failed"),
+            iterations,
+            config,
+        ),
+        // RSA
+        compare(
+            "RSA-2048",
+            "Sign",
+            pool,
+            |s| {
+                s.rsa_private.sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), &Sha256::digest(&s.message))
+                    .expect("RSA signing failed")
+            },
+            iterations,
+            config,
+        ),
+        compare(
+            "RSA-2048",
+            "Verify",
+            pool,
+            |s| {
+                s.rsa_public.verify(Pss::new::<Sha256>(), &Sha256::digest(&s.message), &s.rsa_signature)
+                    .expect("RSA verification failed")
+            },
+            iterations,
+            config,
+        ),
+        // ECDSA
+        compare(
+            "ECDSA P-256",
+            "Sign",
+            pool,
+            |s| -> p256::ecdsa::Signature { s.ecdsa_signing.sign(&s.message) },
+            iterations,
+            config,
+        ),
+        compare(
+            "ECDSA P-256",
+            "Verify",
+            pool,
+            |s| s.ecdsa_verifying.verify(&s.message, &s.ecdsa_signature).expect("ECDSA verification failed"),
+            iterations,
+            config,
+        ),
+        // Dilithium (ML-DSA)
+        compare(
+            "Dilithium (ML-DSA-44)",
+            "Sign",
+            pool,
+            |s| mldsa44::detached_sign(&s.message, &s.dilithium_secret),
+            iterations,
+            config,
+        ),
+        compare(
+            "Dilithium (ML-DSA-44)",
+            "Verify",
+            pool,
+            |s| {
+                mldsa44::verify_detached_signature(&s.dilithium_signature, &s.message, &s.dilithium_public)
+                    .expect("Dilithium verification failed")
+            },
+            iterations,
+            config,
+        ),
+        // Falcon
+        compare(
+            "Falcon-512",
+            "Sign",
+            pool,
+            |s| falcon512::detached_sign(&s.message, &s.falcon_secret),
+            iterations,
+            config,
+        ),
+        compare(
+            "Falcon-512",
+            "Verify",
+            pool,
+            |s| {
+                falcon512::verify_detached_signature(&s.falcon_signature, &s.message, &s.falcon_public)
+                    .expect("Falcon verification failed")
+            },
+            iterations,
+            config,
+        ),
+        // Key exchange terhadap public key peer yang berbeda-beda
+        compare(
+            "X25519 Diffie-Hellman",
+            "Key Exchange",
+            pool,
+            |s| EphemeralSecret::random_from_rng(OsRng).diffie_hellman(&s.x25519_public),
+            iterations,
+            config,
+        ),
+        compare(
+            "ECDH (P-256)",
+            "Key Exchange",
+            pool,
+            |s| P256EphemeralSecret::random(&mut OsRng).diffie_hellman(&s.p256_public),
+            iterations,
+            config,
+        ),
+        // Kyber (ML-KEM)
+        compare(
+            "Kyber (ML-KEM-512)",
+            "Encapsulate",
+            pool,
+            |s| mlkem512::encapsulate(&s.kyber_public),
+            iterations,
+            config,
+        ),
+        compare(
+            "Kyber (ML-KEM-512)",
+            "Decapsulate",
+            pool,
+            |s| mlkem512::decapsulate(&s.kyber_ciphertext, &s.kyber_secret),
+            iterations,
+            config,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evictor_touches_every_line() {
+        let mut evictor = CacheEvictor::new(4 * CACHE_LINE_BYTES);
+        evictor.evict();
+        evictor.evict();
+        assert!(evictor.buffer.chunks(CACHE_LINE_BYTES).all(|line| line[0] == 3));
+    }
+}
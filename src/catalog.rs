@@ -0,0 +1,226 @@
+// Katalog algoritma yang bisa di-benchmark
+//
+// Satu entri per algoritma di suite utama: keluarga (klasik/post-quantum,
+// signature/KEM), level keamanan, operasi yang diukur, ukuran key/signature/
+// ciphertext dan implementasi yang dipakai. Nama sama persis dengan
+// `BenchmarkMetric::name` sehingga bisa dipakai untuk filter `--algorithm`.
+use serde::{Deserialize, Serialize};
+use pqcrypto_mldsa::mldsa44;
+use pqcrypto_falcon::falcon512;
+use pqcrypto_mlkem::mlkem512;
+use crate::constants::{ECDSA_KEY_SIZE, RSA_KEY_SIZE};
+use crate::implementations::{self, DILITHIUM_NAME, KYBER_NAME};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgorithmFamily {
+    Classical,
+    PostQuantum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlgorithmKind {
+    Signature,
+    /// KEM atau key exchange (Diffie-Hellman)
+    Kem,
+}
+
+impl AlgorithmFamily {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlgorithmFamily::Classical => "classical",
+            AlgorithmFamily::PostQuantum => "post-quantum",
+        }
+    }
+}
+
+impl AlgorithmKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            AlgorithmKind::Signature => "signature",
+            AlgorithmKind::Kem => "KEM",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AlgorithmInfo {
+    pub name: String,
+    pub family: AlgorithmFamily,
+    pub kind: AlgorithmKind,
+    /// Kategori keamanan NIST (1–5). `None` untuk algoritma klasik, yang
+    /// tidak punya kategori karena dipatahkan oleh algoritma Shor.
+    pub nist_level: Option<u8>,
+    /// Perkiraan keamanan terhadap penyerang klasik, dalam bit
+    pub classical_security_bits: u16,
+    pub operations: Vec<String>,
+    pub public_key_bytes: usize,
+    pub secret_key_bytes: Option<usize>,
+    pub signature_bytes: Option<usize>,
+    pub ciphertext_bytes: Option<usize>,
+    pub shared_secret_bytes: Option<usize>,
+    pub implementations: Vec<String>,
+    pub notes: Option<String>,
+}
+
+fn operations(ops: &[&str]) -> Vec<String> {
+    ops.iter().map(|op| op.to_string()).collect()
+}
+
+/// Semua algoritma di suite utama, urutan sama dengan report
+pub fn catalog() -> Vec<AlgorithmInfo> {
+    let pq_implementations: Vec<String> =
+        implementations::available_implementations().into_iter().map(String::from).collect();
+    let signature_ops = operations(&["Keygen", "Sign", "Verify"]);
+    let exchange_ops = operations(&["Keygen", "Key Exchange"]);
+
+    vec![
+        AlgorithmInfo {
+            name: "Ed25519".to_string(),
+            family: AlgorithmFamily::Classical,
+            kind: AlgorithmKind::Signature,
+            nist_level: None,
+            classical_security_bits: 128,
+            operations: signature_ops.clone(),
+            public_key_bytes: ed25519_dalek::PUBLIC_KEY_LENGTH,
+            secret_key_bytes: Some(ed25519_dalek::SECRET_KEY_LENGTH),
+            signature_bytes: Some(ed25519_dalek::SIGNATURE_LENGTH),
+            ciphertext_bytes: None,
+            shared_secret_bytes: None,
+            implementations: vec!["ed25519-dalek".to_string()],
+            notes: None,
+        },
+        AlgorithmInfo {
+            name: "RSA-2048".to_string(),
+            family: AlgorithmFamily::Classical,
+            kind: AlgorithmKind::Signature,
+            nist_level: None,
+            classical_security_bits: 112,
+            operations: signature_ops.clone(),
+            public_key_bytes: RSA_KEY_SIZE / 8,
+            secret_key_bytes: None,
+            signature_bytes: Some(RSA_KEY_SIZE / 8),
+            ciphertext_bytes: None,
+            shared_secret_bytes: None,
+            implementations: vec!["rsa".to_string()],
+            notes: Some("PSS with SHA-256; public key size is the modulus, the PKCS#8 secret key is ~1.2 KB".to_string()),
+        },
+        AlgorithmInfo {
+            name: "ECDSA P-256".to_string(),
+            family: AlgorithmFamily::Classical,
+            kind: AlgorithmKind::Signature,
+            nist_level: None,
+            classical_security_bits: 128,
+            operations: signature_ops.clone(),
+            public_key_bytes: 1 + 2 * ECDSA_KEY_SIZE,
+            secret_key_bytes: Some(ECDSA_KEY_SIZE),
+            signature_bytes: Some(2 * ECDSA_KEY_SIZE),
+            ciphertext_bytes: None,
+            shared_secret_bytes: None,
+            implementations: vec!["p256".to_string()],
+            notes: Some("uncompressed SEC1 public key (33 bytes compressed); fixed-size r||s signature".to_string()),
+        },
+        AlgorithmInfo {
+            name: DILITHIUM_NAME.to_string(),
+            family: AlgorithmFamily::PostQuantum,
+            kind: AlgorithmKind::Signature,
+            nist_level: Some(2),
+            classical_security_bits: 128,
+            operations: signature_ops.clone(),
+            public_key_bytes: mldsa44::public_key_bytes(),
+            secret_key_bytes: Some(mldsa44::secret_key_bytes()),
+            signature_bytes: Some(mldsa44::signature_bytes()),
+            ciphertext_bytes: None,
+            shared_secret_bytes: None,
+            implementations: pq_implementations.clone(),
+            notes: None,
+        },
+        AlgorithmInfo {
+            name: "Falcon-512".to_string(),
+            family: AlgorithmFamily::PostQuantum,
+            kind: AlgorithmKind::Signature,
+            nist_level: Some(1),
+            classical_security_bits: 128,
+            operations: signature_ops,
+            public_key_bytes: falcon512::public_key_bytes(),
+            secret_key_bytes: Some(falcon512::secret_key_bytes()),
+            signature_bytes: Some(falcon512::signature_bytes()),
+            ciphertext_bytes: None,
+            shared_secret_bytes: None,
+            implementations: vec![implementations::IMPL_PQCRYPTO.to_string()],
+            notes: Some("signature length varies; the size shown is the maximum".to_string()),
+        },
+        AlgorithmInfo {
+            name: "X25519 Diffie-Hellman".to_string(),
+            family: AlgorithmFamily::Classical,
+            kind: AlgorithmKind::Kem,
+            nist_level: None,
+            classical_security_bits: 128,
+            operations: exchange_ops.clone(),
+            public_key_bytes: 32,
+            secret_key_bytes: Some(32),
+            signature_bytes: None,
+            ciphertext_bytes: None,
+            shared_secret_bytes: Some(32),
+            implementations: vec!["x25519-dalek".to_string()],
+            notes: Some("key exchange: the ephemeral public key is sent instead of a ciphertext".to_string()),
+        },
+        AlgorithmInfo {
+            name: "ECDH (P-256)".to_string(),
+            family: AlgorithmFamily::Classical,
+            kind: AlgorithmKind::Kem,
+            nist_level: None,
+            classical_security_bits: 128,
+            operations: exchange_ops,
+            public_key_bytes: 1 + 2 * ECDSA_KEY_SIZE,
+            secret_key_bytes: Some(ECDSA_KEY_SIZE),
+            signature_bytes: None,
+            ciphertext_bytes: None,
+            shared_secret_bytes: Some(ECDSA_KEY_SIZE),
+            implementations: vec!["p256".to_string()],
+            notes: Some("key exchange: the ephemeral public key is sent instead of a ciphertext".to_string()),
+        },
+        AlgorithmInfo {
+            name: KYBER_NAME.to_string(),
+            family: AlgorithmFamily::PostQuantum,
+            kind: AlgorithmKind::Kem,
+            nist_level: Some(1),
+            classical_security_bits: 128,
+            operations: operations(&["Keygen", "Encapsulate", "Decapsulate"]),
+            public_key_bytes: mlkem512::public_key_bytes(),
+            secret_key_bytes: Some(mlkem512::secret_key_bytes()),
+            signature_bytes: None,
+            ciphertext_bytes: Some(mlkem512::ciphertext_bytes()),
+            shared_secret_bytes: Some(mlkem512::shared_secret_bytes()),
+            implementations: pq_implementations,
+            notes: None,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pqcrypto_traits::kem::{Ciphertext as _, PublicKey as _};
+    use pqcrypto_traits::sign::DetachedSignature as _;
+
+    #[test]
+    fn test_catalog_sizes_match_generated_values() {
+        let entries = catalog();
+        let find = |name: &str| entries.iter().find(|e| e.name == name).expect("missing catalog entry");
+
+        let (public, _) = mlkem512::keypair();
+        let (_, ciphertext) = mlkem512::encapsulate(&public);
+        assert_eq!(find(KYBER_NAME).public_key_bytes, public.as_bytes().len());
+        assert_eq!(find(KYBER_NAME).ciphertext_bytes, Some(ciphertext.as_bytes().len()));
+
+        let (_, secret) = mldsa44::keypair();
+        let signature = mldsa44::detached_sign(b"catalog", &secret);
+        assert_eq!(find(DILITHIUM_NAME).signature_bytes, Some(signature.as_bytes().len()));
+
+        let signing = p256::ecdsa::SigningKey::random(&mut rand::rngs::OsRng);
+        let encoded = signing.verifying_key().to_encoded_point(false);
+        assert_eq!(find("ECDSA P-256").public_key_bytes, encoded.len());
+    }
+}
@@ -0,0 +1,486 @@
+// Command-line interface (clap)
+//
+// Tanpa subcommand, opsi `run` berlaku langsung (`crypto_benchmark --json`
+// sama dengan `crypto_benchmark run --json`). Setiap subcommand punya
+// `--help` sendiri; lihat `exit` untuk exit code.
+use std::path::PathBuf;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use crypto_benchmark::baseline::MetricThreshold;
+use crypto_benchmark::catalog::{self, AlgorithmKind};
+use crypto_benchmark::models::ReferenceAlgorithms;
+use crypto_benchmark::normalize;
+use crypto_benchmark::export::{Column, ExportOptions, SortKey};
+use crypto_benchmark::preflight::Strictness;
+use crypto_benchmark::{DEFAULT_BASELINE_DIR, DEFAULT_REGRESSION_THRESHOLD_PERCENT};
+use crypto_benchmark::{MeasurementConfig, DEFAULT_BATCH_SIZES, DEFAULT_KEY_POOL_SIZE, DEFAULT_SERVER_PORT};
+use crypto_benchmark::{COMPARE_MIN_CHANGE_PERCENT, COMPARE_SIGNIFICANCE_LEVEL, DEFAULT_SWEEP_MESSAGE_SIZES, THROUGHPUT_DURATION_MS};
+
+/// Exit code proses selain 0 (sukses). Argumen tidak valid = 2 (dari clap).
+pub mod exit {
+    /// Benchmark, penulisan report atau server gagal
+    pub const FAILURE: u8 = 1;
+    /// `--preflight=strict` menolak environment yang noisy
+    pub const PREFLIGHT_REFUSED: u8 = 3;
+    /// `baseline check` menemukan regresi di atas threshold
+    pub const REGRESSION: u8 = 4;
+    /// `baseline check` menemukan metric baseline yang hilang dari run sekarang
+    pub const MISSING_METRICS: u8 = 5;
+}
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "crypto_benchmark",
+    version,
+    about = "Classical & post-quantum cryptography benchmarks",
+    disable_version_flag = true,
+    args_conflicts_with_subcommands = true,
+    after_help = "Without a subcommand, `run` is used. See README.md for details."
+)]
+pub struct Cli {
+    /// Show version information
+    #[arg(short = 'v', long, action = ArgAction::Version)]
+    version: Option<bool>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+// Di-parse sekali per proses, ukuran variant tidak penting
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run the benchmark suite (default)
+    Run(RunArgs),
+    /// Start the web interface
+    Serve(ServeArgs),
+    /// List algorithms, operations, sizes and implementations
+    List(ListArgs),
+    /// Time key generation for each algorithm
+    Keygen(KeygenArgs),
+    /// Multi-threaded ops/sec and scaling efficiency
+    Throughput(ThroughputArgs),
+    /// A/B comparison of two saved reports or two run configurations
+    #[command(alias = "comparison")]
+    Compare(CompareArgs),
+    /// Save named baselines and check later runs for regressions
+    Baseline(BaselineArgs),
+    /// Write a self-contained HTML report (inline CSS/JS and charts, works offline)
+    Html(HtmlArgs),
+    /// Print the report JSON Schema, or upgrade an older report to the current schema
+    Schema(SchemaArgs),
+    /// Deprecated: runs the optimized suite
+    #[command(hide = true)]
+    Legacy,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct HtmlArgs {
+    /// HTML file to write
+    #[arg(value_name = "FILE")]
+    pub path: PathBuf,
+    /// Render this JSON report instead of running the suite
+    #[arg(long, value_name = "REPORT")]
+    pub from: Option<PathBuf>,
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SchemaArgs {
+    /// Print this report upgraded to the current schema version instead
+    #[arg(long, value_name = "REPORT")]
+    pub upgrade: Option<PathBuf>,
+}
+
+/// Format report di stdout
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable console output
+    #[default]
+    Table,
+    Json,
+    Csv,
+    /// GitHub-flavored Markdown table
+    #[value(alias = "markdown")]
+    Md,
+}
+
+/// Opsi output bersama: format stdout dan/atau JSON ke file
+#[derive(Debug, Clone, Default, Args)]
+pub struct OutputArgs {
+    /// Print the report as JSON instead of text (same as --format json)
+    #[arg(long)]
+    pub json: bool,
+    /// Report format on stdout
+    #[arg(long, value_enum, default_value_t, conflicts_with = "json")]
+    pub format: OutputFormat,
+    /// Columns for csv/md, comma-separated (e.g. name,operation,mean_us,ops_per_sec)
+    #[arg(long, value_delimiter = ',', value_parser = parse_column, value_name = "COLUMNS")]
+    pub columns: Vec<Column>,
+    /// Sort csv/md rows by COLUMN, ascending or with :desc (e.g. mean_us:desc)
+    #[arg(long, value_parser = parse_sort_key, value_name = "COLUMN[:desc]")]
+    pub sort: Option<SortKey>,
+    /// Also write the JSON report to PATH
+    #[arg(short, long, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+    /// Leave the hostname out of the report's environment metadata
+    #[arg(long)]
+    pub no_hostname: bool,
+}
+
+impl OutputArgs {
+    pub fn format(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else {
+            self.format
+        }
+    }
+
+    /// Stdout dipakai untuk report, jadi progress/banner ditahan
+    pub fn machine_readable(&self) -> bool {
+        self.format() != OutputFormat::Table
+    }
+
+    pub fn export_options(&self) -> ExportOptions {
+        let columns = if self.columns.is_empty() { Column::DEFAULT.to_vec() } else { self.columns.clone() };
+        ExportOptions { columns, sort: self.sort }
+    }
+}
+
+/// Pemilihan algoritma dan jumlah sample
+#[derive(Debug, Clone, Default, Args)]
+pub struct SelectArgs {
+    /// Only benchmark algorithms whose name contains NAME (repeatable, e.g. -a ed25519 -a ml-kem)
+    #[arg(short, long = "algorithm", value_name = "NAME")]
+    pub algorithms: Vec<String>,
+    /// Samples per operation, overriding each benchmark's default
+    #[arg(short = 'n', long, value_name = "N", value_parser = parse_positive)]
+    pub iterations: Option<usize>,
+    /// Signature algorithm that relative factors are computed against [default: Ed25519]
+    #[arg(long, value_name = "NAME", value_parser = parse_signature_reference)]
+    pub reference_signature: Option<String>,
+    /// KEM / key exchange that relative factors are computed against [default: X25519]
+    #[arg(long, value_name = "NAME", value_parser = parse_kem_reference)]
+    pub reference_kem: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Args)]
+pub struct RunArgs {
+    #[command(flatten)]
+    pub output: OutputArgs,
+    #[command(flatten)]
+    pub select: SelectArgs,
+
+    /// Also report CPU cycles per operation
+    #[arg(long)]
+    pub cycles: bool,
+    /// Also report instructions/branch/cache misses (Linux)
+    #[arg(long)]
+    pub perf_counters: bool,
+    /// Also report heap allocations (needs --features alloc-tracking)
+    #[arg(long)]
+    pub allocations: bool,
+    /// Also report peak stack usage per operation
+    #[arg(long)]
+    pub stack: bool,
+
+    /// Signature message-size sweep [default sizes: 32,1K,64K,1M]
+    #[arg(long, value_name = "SIZES", require_equals = true, value_parser = parse_size_list)]
+    pub sweep: Option<Option<SizeList>>,
+    /// Also measure cold-cache latency next to warm-cache
+    #[arg(long)]
+    pub cold: bool,
+    /// Verify across N distinct keypairs/signatures [default: 32]
+    #[arg(long, value_name = "N", require_equals = true, value_parser = parse_positive)]
+    pub key_pool: Option<Option<usize>>,
+    /// Ed25519 batch verification [default sizes: 4,16,64,256]
+    #[arg(long, value_name = "SIZES", require_equals = true, value_parser = parse_size_list)]
+    pub batch_verify: Option<Option<SizeList>>,
+    /// Also time key/signature/ciphertext encode and decode
+    #[arg(long)]
+    pub serialization: bool,
+    /// Compare ML-KEM/ML-DSA implementations (see --features rustcrypto)
+    #[arg(long)]
+    pub implementations: bool,
+
+    /// Check governor/turbo/load before measuring [default mode: warn]
+    #[arg(long, value_name = "warn|strict", require_equals = true, value_parser = parse_strictness)]
+    pub preflight: Option<Option<Strictness>>,
+    /// Pin the benchmark thread to CPU N (Linux, implies --preflight)
+    #[arg(long, value_name = "N")]
+    pub pin_core: Option<usize>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ServeArgs {
+    /// Port to listen on
+    #[arg(short, long, default_value_t = DEFAULT_SERVER_PORT)]
+    pub port: u16,
+    /// Leave the hostname out of the reports served by /api/benchmarks
+    #[arg(long)]
+    pub no_hostname: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ListArgs {
+    /// Print the catalog as JSON instead of text
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct KeygenArgs {
+    #[command(flatten)]
+    pub output: OutputArgs,
+    #[command(flatten)]
+    pub select: SelectArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+#[command(after_help = "Examples:\n  \
+    crypto_benchmark compare before.json after.json\n  \
+    crypto_benchmark compare --config-a=\"-a ed25519\" --config-b=\"-a ed25519 --pin-core=2\"")]
+pub struct CompareArgs {
+    /// Saved JSON reports (from --output or --json): baseline, then candidate
+    #[arg(
+        num_args = 2,
+        value_names = ["BASELINE", "CANDIDATE"],
+        required_unless_present_all = ["config_a", "config_b"],
+        conflicts_with_all = ["config_a", "config_b"]
+    )]
+    pub reports: Vec<PathBuf>,
+    /// Run options for the baseline, e.g. "-a ed25519 -n 200" (whitespace separated)
+    #[arg(long, value_name = "RUN OPTIONS", requires = "config_b", allow_hyphen_values = true, value_parser = parse_run_options)]
+    pub config_a: Option<RunArgs>,
+    /// Run options for the candidate
+    #[arg(long, value_name = "RUN OPTIONS", requires = "config_a", allow_hyphen_values = true, value_parser = parse_run_options)]
+    pub config_b: Option<RunArgs>,
+    /// Print the comparison as JSON instead of text
+    #[arg(long)]
+    pub json: bool,
+    /// p-value below which a difference counts as significant
+    #[arg(long, value_name = "P", default_value_t = COMPARE_SIGNIFICANCE_LEVEL)]
+    pub alpha: f64,
+    /// Significant changes smaller than this many percent count as unchanged
+    #[arg(long, value_name = "PERCENT", default_value_t = COMPARE_MIN_CHANGE_PERCENT)]
+    pub min_change: f64,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BaselineArgs {
+    /// Directory holding baseline reports
+    #[arg(long, value_name = "DIR", default_value = DEFAULT_BASELINE_DIR, global = true)]
+    pub dir: PathBuf,
+    #[command(subcommand)]
+    pub command: BaselineCommand,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum BaselineCommand {
+    /// Run the suite (or take an existing report) and save it as NAME
+    Save(BaselineSaveArgs),
+    /// Run the suite and fail if it regressed against baseline NAME
+    #[command(after_help = "Exit code 4 when a metric regressed significantly by more than its threshold.\n\
+        Example: baseline check main --threshold 5 --metric-threshold \"RSA-2048 Sign=15\"")]
+    Check(BaselineCheckArgs),
+    /// List saved baselines
+    List,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BaselineSaveArgs {
+    /// Baseline name (letters, digits, '.', '_' or '-')
+    pub name: String,
+    /// Save this JSON report instead of running the suite
+    #[arg(long, value_name = "REPORT")]
+    pub from: Option<PathBuf>,
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct BaselineCheckArgs {
+    /// Baseline name to compare against
+    pub name: String,
+    /// Check this JSON report instead of running the suite
+    #[arg(long, value_name = "REPORT")]
+    pub from: Option<PathBuf>,
+    /// Regression threshold in percent for metrics without a specific one
+    #[arg(long, value_name = "PERCENT", default_value_t = DEFAULT_REGRESSION_THRESHOLD_PERCENT)]
+    pub threshold: f64,
+    /// Per-metric threshold, e.g. "RSA-2048 Sign=15" or "ml-kem=5" (repeatable, most specific wins)
+    #[arg(long, value_name = "PATTERN=PERCENT", value_parser = parse_metric_threshold)]
+    pub metric_threshold: Vec<MetricThreshold>,
+    /// p-value below which a difference counts as significant
+    #[arg(long, value_name = "P", default_value_t = COMPARE_SIGNIFICANCE_LEVEL)]
+    pub alpha: f64,
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ThroughputArgs {
+    #[command(flatten)]
+    pub output: OutputArgs,
+    #[command(flatten)]
+    pub select: SelectArgs,
+    /// Highest thread count to test [default: available parallelism]
+    #[arg(short = 't', long, value_name = "N", value_parser = parse_positive)]
+    pub threads: Option<usize>,
+    /// Duration of each (operation, thread count) run in milliseconds
+    #[arg(short, long, value_name = "MS", default_value_t = THROUGHPUT_DURATION_MS)]
+    pub duration_ms: u64,
+}
+
+/// Daftar ukuran dipisah koma, dengan suffix K/M opsional
+#[derive(Debug, Clone, PartialEq)]
+pub struct SizeList(pub Vec<usize>);
+
+#[derive(Debug, Clone, Copy)]
+pub struct PreflightOptions {
+    pub strictness: Strictness,
+    pub pin_core: Option<usize>,
+}
+
+impl SelectArgs {
+    pub fn measurement_config(&self) -> MeasurementConfig {
+        MeasurementConfig {
+            iterations: self.iterations,
+            algorithms: self.algorithms.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn references(&self) -> ReferenceAlgorithms {
+        let defaults = ReferenceAlgorithms::default();
+        ReferenceAlgorithms {
+            signature: self.reference_signature.clone().unwrap_or(defaults.signature),
+            kem: self.reference_kem.clone().unwrap_or(defaults.kem),
+        }
+    }
+}
+
+impl RunArgs {
+    pub fn measurement_config(&self) -> MeasurementConfig {
+        MeasurementConfig {
+            count_cycles: self.cycles,
+            hardware_counters: self.perf_counters,
+            track_allocations: self.allocations,
+            measure_stack: self.stack,
+            ..self.select.measurement_config()
+        }
+    }
+
+    pub fn sweep_sizes(&self) -> Option<Vec<usize>> {
+        self.sweep.as_ref().map(|sizes| sizes.as_ref().map_or(DEFAULT_SWEEP_MESSAGE_SIZES.to_vec(), |s| s.0.clone()))
+    }
+
+    pub fn batch_sizes(&self) -> Option<Vec<usize>> {
+        self.batch_verify.as_ref().map(|sizes| sizes.as_ref().map_or(DEFAULT_BATCH_SIZES.to_vec(), |s| s.0.clone()))
+    }
+
+    pub fn key_pool_size(&self) -> Option<usize> {
+        self.key_pool.map(|size| size.unwrap_or(DEFAULT_KEY_POOL_SIZE))
+    }
+
+    /// `--pin-core` tanpa `--preflight` tetap menjalankan pre-flight (mode warn)
+    pub fn preflight(&self) -> Option<PreflightOptions> {
+        if self.preflight.is_none() && self.pin_core.is_none() {
+            return None;
+        }
+        Some(PreflightOptions {
+            strictness: self.preflight.flatten().unwrap_or_default(),
+            pin_core: self.pin_core,
+        })
+    }
+}
+
+/// Opsi `run` di dalam satu string, untuk `compare --config-a/--config-b`
+#[derive(Debug, Parser)]
+#[command(name = "run", no_binary_name = true)]
+struct RunOptions {
+    #[command(flatten)]
+    args: RunArgs,
+}
+
+fn parse_run_options(value: &str) -> Result<RunArgs, String> {
+    RunOptions::try_parse_from(value.split_whitespace())
+        .map(|options| options.args)
+        .map_err(|e| e.to_string().lines().next().unwrap_or_default().trim_start_matches("error: ").to_string())
+}
+
+fn parse_metric_threshold(value: &str) -> Result<MetricThreshold, String> {
+    let (pattern, percent) = value
+        .rsplit_once('=')
+        .ok_or_else(|| format!("expected PATTERN=PERCENT, got '{}'", value))?;
+    let percent = percent
+        .trim()
+        .trim_end_matches('%')
+        .parse::<f64>()
+        .map_err(|_| format!("invalid percentage in '{}'", value))?;
+    Ok(MetricThreshold { pattern: pattern.trim().to_string(), percent })
+}
+
+fn parse_positive(value: &str) -> Result<usize, String> {
+    match value.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => Err(format!("expected a positive integer, got '{}'", value)),
+    }
+}
+
+fn parse_column(value: &str) -> Result<Column, String> {
+    Column::parse(value.trim()).ok_or_else(|| {
+        let known: Vec<&str> = Column::ALL.iter().map(|c| c.id()).collect();
+        format!("unknown column '{}' (expected one of {})", value, known.join(", "))
+    })
+}
+
+fn parse_sort_key(value: &str) -> Result<SortKey, String> {
+    SortKey::parse(value.trim()).ok_or_else(|| format!("expected COLUMN or COLUMN:desc, got '{}'", value))
+}
+
+fn parse_reference(value: &str, kind: AlgorithmKind) -> Result<String, String> {
+    normalize::resolve_reference(value, kind).ok_or_else(|| {
+        let names: Vec<String> =
+            catalog::catalog().into_iter().filter(|info| info.kind == kind).map(|info| info.name).collect();
+        format!("no {} algorithm matches '{}' (expected one of: {})", kind.label(), value, names.join(", "))
+    })
+}
+
+fn parse_signature_reference(value: &str) -> Result<String, String> {
+    parse_reference(value, AlgorithmKind::Signature)
+}
+
+fn parse_kem_reference(value: &str) -> Result<String, String> {
+    parse_reference(value, AlgorithmKind::Kem)
+}
+
+fn parse_strictness(value: &str) -> Result<Strictness, String> {
+    Strictness::parse(value).ok_or_else(|| format!("expected warn or strict, got '{}'", value))
+}
+
+/// `32,1K,64K,1M` → [32, 1024, 65536, 1048576]
+pub fn parse_size_list(value: &str) -> Result<SizeList, String> {
+    value
+        .split(',')
+        .map(|size| {
+            let size = size.trim();
+            let (digits, multiplier) = match size.chars().last() {
+                Some('K') | Some('k') => (&size[..size.len() - 1], 1024),
+                Some('M') | Some('m') => (&size[..size.len() - 1], 1024 * 1024),
+                _ => (size, 1),
+            };
+            digits
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_mul(multiplier))
+                .filter(|n| *n > 0)
+                .ok_or_else(|| format!("invalid size '{}'", size))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(SizeList)
+}
@@ -0,0 +1,328 @@
+// A/B comparison antara dua BenchmarkReport
+//
+// Metric dipasangkan berdasarkan (section, algoritma, operasi, implementasi,
+// ukuran pesan). Untuk setiap pasangan dihitung selisih mean, persentase
+// perubahan dan Welch's t-test dari mean/std dev/jumlah sample yang sudah ada
+// di report, jadi report lama yang tersimpan juga bisa dibandingkan.
+use serde::{Deserialize, Serialize};
+use crate::constants::{COMPARE_MIN_CHANGE_PERCENT, COMPARE_SIGNIFICANCE_LEVEL};
+use crate::models::{BenchmarkMetric, BenchmarkReport};
+
+/// Klasifikasi satu metric. Semua metric adalah latency: lebih kecil lebih baik.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Improved,
+    Regressed,
+    Unchanged,
+}
+
+impl Verdict {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Improved => "improved",
+            Verdict::Regressed => "regressed",
+            Verdict::Unchanged => "unchanged",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct CompareOptions {
+    /// p-value di bawah nilai ini dianggap signifikan
+    pub significance_level: f64,
+    /// Perubahan signifikan yang lebih kecil dari ini tetap "unchanged"
+    pub min_change_percent: f64,
+}
+
+impl Default for CompareOptions {
+    fn default() -> Self {
+        CompareOptions {
+            significance_level: COMPARE_SIGNIFICANCE_LEVEL,
+            min_change_percent: COMPARE_MIN_CHANGE_PERCENT,
+        }
+    }
+}
+
+/// Ringkasan sample yang dibutuhkan t-test
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub mean: f64,
+    pub std_dev: f64,
+    pub n: usize,
+}
+
+impl From<&BenchmarkMetric> for Sample {
+    fn from(metric: &BenchmarkMetric) -> Self {
+        Sample { mean: metric.mean_micros, std_dev: metric.std_dev_micros, n: metric.iterations }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct WelchTest {
+    pub t_statistic: f64,
+    pub degrees_of_freedom: f64,
+    /// Two-sided p-value
+    pub p_value: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricDelta {
+    pub section: String,
+    pub name: String,
+    pub operation: String,
+    #[serde(default)]
+    pub implementation: Option<String>,
+    #[serde(default)]
+    pub message_size: Option<usize>,
+    pub baseline_mean_micros: f64,
+    pub candidate_mean_micros: f64,
+    pub delta_micros: f64,
+    pub percent_change: f64,
+    pub test: WelchTest,
+    pub verdict: Verdict,
+}
+
+impl MetricDelta {
+    /// Mis. "Ed25519 Verify", "Kyber (ML-KEM-512) Keygen [RustCrypto]", "RSA-2048 Sign @ 1024 B"
+    pub fn label(&self) -> String {
+        let mut label = format!("{} {}", self.name, self.operation);
+        if let Some(implementation) = &self.implementation {
+            label.push_str(&format!(" [{}]", implementation));
+        }
+        if let Some(size) = self.message_size {
+            label.push_str(&format!(" @ {} B", size));
+        }
+        label
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonReport {
+    pub significance_level: f64,
+    pub min_change_percent: f64,
+    pub deltas: Vec<MetricDelta>,
+    /// Metric yang hanya ada di salah satu report (label dengan prefix section)
+    pub only_in_baseline: Vec<String>,
+    pub only_in_candidate: Vec<String>,
+}
+
+impl ComparisonReport {
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.deltas.iter().filter(|d| d.verdict == verdict).count()
+    }
+}
+
+type MetricKey<'a> = (&'a str, &'a str, &'a str, Option<&'a str>, Option<usize>);
+
+fn key<'a>(section: &'a str, metric: &'a BenchmarkMetric) -> MetricKey<'a> {
+    (section, &metric.name, &metric.operation, metric.implementation.as_deref(), metric.message_size)
+}
+
+fn prefixed_label(section: &str, metric: &BenchmarkMetric) -> String {
+    let mut label = format!("{}: {} {}", section, metric.name, metric.operation);
+    if let Some(implementation) = &metric.implementation {
+        label.push_str(&format!(" [{}]", implementation));
+    }
+    if let Some(size) = metric.message_size {
+        label.push_str(&format!(" @ {} B", size));
+    }
+    label
+}
+
+/// Bandingkan `candidate` terhadap `baseline`, urutan mengikuti baseline
+pub fn compare_reports(baseline: &BenchmarkReport, candidate: &BenchmarkReport, options: &CompareOptions) -> ComparisonReport {
+    let baseline_metrics = baseline.metrics();
+    let candidate_metrics = candidate.metrics();
+
+    let mut deltas = Vec::new();
+    let mut only_in_baseline = Vec::new();
+    for &(section, base) in &baseline_metrics {
+        match candidate_metrics.iter().find(|(s, c)| key(s, c) == key(section, base)) {
+            Some(&(_, cand)) => deltas.push(compare_metric(section, base, cand, options)),
+            None => only_in_baseline.push(prefixed_label(section, base)),
+        }
+    }
+    let only_in_candidate = candidate_metrics
+        .iter()
+        .filter(|(s, c)| !baseline_metrics.iter().any(|(bs, b)| key(bs, b) == key(s, c)))
+        .map(|(s, c)| prefixed_label(s, c))
+        .collect();
+
+    ComparisonReport {
+        significance_level: options.significance_level,
+        min_change_percent: options.min_change_percent,
+        deltas,
+        only_in_baseline,
+        only_in_candidate,
+    }
+}
+
+fn compare_metric(section: &str, baseline: &BenchmarkMetric, candidate: &BenchmarkMetric, options: &CompareOptions) -> MetricDelta {
+    let delta = candidate.mean_micros - baseline.mean_micros;
+    let percent_change = if baseline.mean_micros > 0.0 { delta / baseline.mean_micros * 100.0 } else { 0.0 };
+    let test = welch_t_test(Sample::from(baseline), Sample::from(candidate));
+
+    let verdict = if test.p_value >= options.significance_level || percent_change.abs() < options.min_change_percent {
+        Verdict::Unchanged
+    } else if delta < 0.0 {
+        Verdict::Improved
+    } else {
+        Verdict::Regressed
+    };
+
+    MetricDelta {
+        section: section.to_string(),
+        name: baseline.name.clone(),
+        operation: baseline.operation.clone(),
+        implementation: baseline.implementation.clone(),
+        message_size: baseline.message_size,
+        baseline_mean_micros: baseline.mean_micros,
+        candidate_mean_micros: candidate.mean_micros,
+        delta_micros: delta,
+        percent_change,
+        test,
+        verdict,
+    }
+}
+
+/// Welch's unequal-variance t-test (two-sided). Kurang dari dua sample di
+/// salah satu sisi berarti tidak ada bukti perbedaan (p = 1).
+pub fn welch_t_test(a: Sample, b: Sample) -> WelchTest {
+    if a.n < 2 || b.n < 2 {
+        return WelchTest { t_statistic: 0.0, degrees_of_freedom: 0.0, p_value: 1.0 };
+    }
+    let (n_a, n_b) = (a.n as f64, b.n as f64);
+    let var_a = a.std_dev * a.std_dev / n_a;
+    let var_b = b.std_dev * b.std_dev / n_b;
+    let standard_error = (var_a + var_b).sqrt();
+
+    if standard_error == 0.0 {
+        // Tanpa variasi sama sekali: beda mean sekecil apa pun adalah pasti
+        let differs = a.mean != b.mean;
+        return WelchTest {
+            t_statistic: if differs { (b.mean - a.mean).signum() * f64::INFINITY } else { 0.0 },
+            degrees_of_freedom: n_a + n_b - 2.0,
+            p_value: if differs { 0.0 } else { 1.0 },
+        };
+    }
+
+    let t = (b.mean - a.mean) / standard_error;
+    let df = (var_a + var_b).powi(2) / (var_a.powi(2) / (n_a - 1.0) + var_b.powi(2) / (n_b - 1.0));
+    // P(|T| > t) = I_{df/(df+t²)}(df/2, 1/2)
+    let p_value = regularized_incomplete_beta(df / 2.0, 0.5, df / (df + t * t)).clamp(0.0, 1.0);
+
+    WelchTest { t_statistic: t, degrees_of_freedom: df, p_value }
+}
+
+/// ln Γ(x), aproksimasi Lanczos (g = 7)
+fn ln_gamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // Refleksi: Γ(x)Γ(1-x) = π / sin(πx)
+        return (std::f64::consts::PI / (std::f64::consts::PI * x).sin()).ln() - ln_gamma(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |acc, (i, c)| acc + c / (x + i as f64 + 1.0));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+/// I_x(a, b) lewat continued fraction (modified Lentz)
+fn regularized_incomplete_beta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    // Continued fraction konvergen cepat untuk x < (a+1)/(a+b+2)
+    if x > (a + 1.0) / (a + b + 2.0) {
+        return 1.0 - regularized_incomplete_beta(b, a, 1.0 - x);
+    }
+
+    let front = (ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp() / a;
+
+    const TINY: f64 = 1e-300;
+    const EPSILON: f64 = 1e-14;
+    let mut c = 1.0;
+    let mut d = 1.0 - (a + b) * x / (a + 1.0);
+    if d.abs() < TINY {
+        d = TINY;
+    }
+    d = 1.0 / d;
+    let mut fraction = d;
+
+    for m in 1..=300 {
+        let m = m as f64;
+        for numerator in [
+            m * (b - m) * x / ((a + 2.0 * m - 1.0) * (a + 2.0 * m)),
+            -(a + m) * (a + b + m) * x / ((a + 2.0 * m) * (a + 2.0 * m + 1.0)),
+        ] {
+            d = 1.0 + numerator * d;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = 1.0 + numerator / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            fraction *= c * d;
+        }
+        if (c * d - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+    front * fraction
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_welch_p_value_matches_student_t_table() {
+        // Sample identik ukuran sama: t = 2, df = 18 → p ≈ 0.0608
+        let a = Sample { mean: 10.0, std_dev: 1.0, n: 10 };
+        let b = Sample { mean: 10.0 + 2.0 * (0.2f64).sqrt(), std_dev: 1.0, n: 10 };
+        let test = welch_t_test(a, b);
+        assert!((test.t_statistic - 2.0).abs() < 1e-9);
+        assert!((test.degrees_of_freedom - 18.0).abs() < 1e-9);
+        assert!((test.p_value - 0.0608).abs() < 1e-3, "p = {}", test.p_value);
+
+        assert!((welch_t_test(a, a).p_value - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_verdict_needs_significance_and_min_change() {
+        let options = CompareOptions { significance_level: 0.05, min_change_percent: 1.0 };
+        let sample = |mean: f64, std_dev: f64| BenchmarkMetric {
+            std_dev_micros: std_dev,
+            ..BenchmarkMetric::for_test("Ed25519", "Sign", mean)
+        };
+        let verdict = |base: f64, cand: f64, std_dev: f64| {
+            compare_metric("signatures", &sample(base, std_dev), &sample(cand, std_dev), &options).verdict
+        };
+
+        assert_eq!(verdict(20.0, 16.0, 0.5), Verdict::Improved);
+        assert_eq!(verdict(20.0, 24.0, 0.5), Verdict::Regressed);
+        // Besar tapi noisy: tidak signifikan
+        assert_eq!(verdict(20.0, 24.0, 50.0), Verdict::Unchanged);
+        // Signifikan tapi di bawah min_change_percent
+        assert_eq!(verdict(20.0, 20.1, 0.01), Verdict::Unchanged);
+    }
+}
@@ -1,6 +1,12 @@
 // Constants untuk benchmark configuration
 pub const DEFAULT_MESSAGE: &[u8] = b"Hello, world! This is a test message for cryptographic benchmarking.";
 
+// Message-size sweep untuk signature benchmarks: 32 B, 1 KiB, 64 KiB, 1 MiB
+pub const DEFAULT_SWEEP_MESSAGE_SIZES: &[usize] = &[32, 1024, 64 * 1024, 1024 * 1024];
+
+// Ed25519 batch verification: jumlah signature per batch
+pub const DEFAULT_BATCH_SIZES: &[usize] = &[4, 16, 64, 256];
+
 // Key sizes
 pub const ED25519_KEY_SIZE: usize = 32;
 pub const RSA_KEY_SIZE: usize = 2048;
@@ -11,6 +17,56 @@ pub const DEFAULT_WARMUP_ITERATIONS: usize = 10;
 pub const DEFAULT_MEASUREMENT_ITERATIONS: usize = 100;
 pub const MIN_MEASUREMENT_TIME_MS: u64 = 100;
 
+// Batched sampling untuk operasi yang sangat cepat
+pub const TIMER_CALIBRATION_ROUNDS: usize = 1000;
+pub const TIMER_RESOLUTION_MULTIPLE: u32 = 100;
+pub const MIN_SAMPLE_TIME_NS: u64 = 10_000;
+pub const MAX_CALLS_PER_SAMPLE: usize = 10_000;
+
+// Jumlah pemanggilan untuk pass allocation tracking
+pub const ALLOCATION_SAMPLE_CALLS: usize = 10;
+
+// Stack high-water mark: ukuran thread pengukur dan area yang di-paint
+pub const STACK_THREAD_SIZE: usize = 8 * 1024 * 1024;
+pub const STACK_PAINT_BYTES: usize = 1024 * 1024;
+
+// Throughput mode: durasi per (operasi, jumlah thread)
+pub const THROUGHPUT_DURATION_MS: u64 = 500;
+
+// Port default untuk web interface (`serve --port`)
+pub const DEFAULT_SERVER_PORT: u16 = 3000;
+
+// Cold-cache mode: buffer yang di-touch antar sample (lebih besar dari LLC),
+// jumlah key/pesan/signature set yang dirotasi, dan jumlah sample per operasi
+pub const CACHE_EVICTION_BYTES: usize = 64 * 1024 * 1024;
+pub const COLD_CACHE_KEY_SETS: usize = 16;
+pub const COLD_CACHE_ITERATIONS: usize = 50;
+
+// Jumlah keypair + signature default untuk `--key-pool`
+pub const DEFAULT_KEY_POOL_SIZE: usize = 32;
+
+// Pre-flight: load average per logical CPU dan CPU% proses lain yang dianggap noisy
+pub const PREFLIGHT_MAX_LOAD_PER_CPU: f64 = 0.5;
+pub const PREFLIGHT_BUSY_PROCESS_CPU_PERCENT: f32 = 25.0;
+
+// A/B comparison: p-value maksimum dan perubahan minimum (%) untuk
+// menganggap metric improved/regressed
+pub const COMPARE_SIGNIFICANCE_LEVEL: f64 = 0.05;
+pub const COMPARE_MIN_CHANGE_PERCENT: f64 = 1.0;
+
+// Baseline: direktori penyimpanan dan threshold regresi default (%)
+pub const DEFAULT_BASELINE_DIR: &str = ".benchmark-baselines";
+pub const DEFAULT_REGRESSION_THRESHOLD_PERCENT: f64 = 10.0;
+
+// Versi layout JSON report. Versi 1 = tanpa `schema_version`, ukuran sebagai
+// string di `extra_info`; versi 2 = ukuran bertipe di `sizes`
+pub const REPORT_SCHEMA_VERSION: u32 = 2;
+
+// Algoritma acuan untuk faktor relatif (`normalize`), per jenis algoritma
+pub const REFERENCE_SIGNATURE_ALGORITHM: &str = "Ed25519";
+pub const REFERENCE_KEM_ALGORITHM: &str = "X25519 Diffie-Hellman";
+
 // Resource measurement
 pub const SYSTEM_REFRESH_INTERVAL_MS: u64 = 100;
+#[deprecated(since = "0.2.0", note = "Unused: the RSS poller now samples every SYSTEM_REFRESH_INTERVAL_MS")]
 pub const CPU_SAMPLE_COUNT: usize = 5;
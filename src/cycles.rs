@@ -0,0 +1,157 @@
+// CPU cycle counter untuk perbandingan antar mesin
+//
+// Urutan sumber: RDTSCP (x86_64), CNTVCT_EL0 (aarch64), lalu perf_event_open
+// (Linux) sebagai fallback. Catatan: RDTSCP dan CNTVCT_EL0 berjalan pada
+// frekuensi konstan (TSC / generic timer), bukan frekuensi core aktual, jadi
+// hasilnya dilaporkan sebagai "ticks"; hanya perf_event yang menghitung cycles
+// core yang sebenarnya (ikut berubah dengan turbo / frequency scaling).
+use schemars::JsonSchema;
+use serde::{Serialize, Deserialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CycleSource {
+    Rdtscp,
+    Cntvct,
+    PerfEvent,
+}
+
+impl CycleSource {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CycleSource::Rdtscp => "RDTSCP",
+            CycleSource::Cntvct => "CNTVCT_EL0",
+            CycleSource::PerfEvent => "perf_event",
+        }
+    }
+
+    /// Satuan hitungan dari sumber ini
+    pub fn unit(&self) -> &'static str {
+        match self {
+            CycleSource::Rdtscp => "TSC ticks",
+            CycleSource::Cntvct => "timer ticks",
+            CycleSource::PerfEvent => "cycles",
+        }
+    }
+}
+
+/// Counter yang dibuka untuk thread pemanggil
+pub struct CycleCounter {
+    source: CycleSource,
+    #[cfg(target_os = "linux")]
+    perf: Option<crate::perf::PerfCounter>,
+    overhead: u64,
+}
+
+impl CycleCounter {
+    /// Pilih sumber cycle terbaik yang tersedia. `None` jika tidak ada.
+    pub fn new() -> Option<Self> {
+        let mut counter = Self::open()?;
+        counter.overhead = counter.calibrate_overhead();
+        Some(counter)
+    }
+
+    fn open() -> Option<Self> {
+        #[cfg(target_arch = "x86_64")]
+        if has_rdtscp() {
+            return Some(Self::with_source(CycleSource::Rdtscp));
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        return Some(Self::with_source(CycleSource::Cntvct));
+
+        #[cfg(not(target_arch = "aarch64"))]
+        Self::open_perf()
+    }
+
+    #[allow(dead_code)]
+    fn with_source(source: CycleSource) -> Self {
+        CycleCounter {
+            source,
+            #[cfg(target_os = "linux")]
+            perf: None,
+            overhead: 0,
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    #[cfg_attr(target_arch = "aarch64", allow(dead_code))]
+    fn open_perf() -> Option<Self> {
+        use crate::perf::{PerfCounter, PERF_COUNT_HW_CPU_CYCLES, PERF_TYPE_HARDWARE};
+        let perf = PerfCounter::open(PERF_TYPE_HARDWARE, PERF_COUNT_HW_CPU_CYCLES).ok()?;
+        Some(CycleCounter {
+            source: CycleSource::PerfEvent,
+            perf: Some(perf),
+            overhead: 0,
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    #[cfg_attr(target_arch = "aarch64", allow(dead_code))]
+    fn open_perf() -> Option<Self> {
+        None
+    }
+
+    pub fn source(&self) -> CycleSource {
+        self.source
+    }
+
+    /// Biaya dua pembacaan berturut-turut (median), dalam satuan `source().unit()`
+    pub fn overhead(&self) -> u64 {
+        self.overhead
+    }
+
+    #[inline(always)]
+    pub fn read(&self) -> u64 {
+        match self.source {
+            #[cfg(target_arch = "x86_64")]
+            CycleSource::Rdtscp => {
+                let mut aux = 0u32;
+                unsafe { core::arch::x86_64::__rdtscp(&mut aux) }
+            }
+            #[cfg(target_arch = "aarch64")]
+            CycleSource::Cntvct => {
+                let value: u64;
+                unsafe {
+                    core::arch::asm!("isb", "mrs {}, cntvct_el0", out(reg) value, options(nostack));
+                }
+                value
+            }
+            #[cfg(target_os = "linux")]
+            CycleSource::PerfEvent => self
+                .perf
+                .as_ref()
+                .and_then(|perf| perf.read().ok())
+                .unwrap_or(0),
+            #[allow(unreachable_patterns)]
+            _ => 0,
+        }
+    }
+
+    fn calibrate_overhead(&self) -> u64 {
+        let mut samples: Vec<u64> = (0..100)
+            .map(|_| {
+                let start = self.read();
+                self.read().saturating_sub(start)
+            })
+            .collect();
+        samples.sort_unstable();
+        samples[samples.len() / 2]
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn has_rdtscp() -> bool {
+    use core::arch::x86_64::__cpuid;
+
+    // CPUID.80000001H:EDX[27] = RDTSCP
+    #[allow(unused_unsafe)]
+    let features = unsafe {
+        if __cpuid(0x8000_0000).eax >= 0x8000_0001 {
+            __cpuid(0x8000_0001).edx
+        } else {
+            0
+        }
+    };
+    features & (1 << 27) != 0
+}
@@ -0,0 +1,121 @@
+// Metadata environment: mesin, toolchain dan konfigurasi sebuah run
+//
+// Info build (rustc, target, opt-level, target features, versi dependency)
+// ditanam oleh build.rs lewat `env!`; info mesin dibaca saat run lewat
+// sysinfo dan sysfs (governor/turbo, sama seperti pre-flight).
+use std::collections::BTreeMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+use sysinfo::{CpuRefreshKind, MemoryRefreshKind, RefreshKind, System};
+use crate::measurement::MeasurementConfig;
+use crate::models::EnvironmentMetadata;
+use crate::preflight;
+
+/// Kumpulkan metadata untuk report. `include_hostname = false` untuk report
+/// yang akan dibagikan (`--no-hostname`).
+pub fn capture(config: &MeasurementConfig, include_hostname: bool) -> EnvironmentMetadata {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+    let sys = System::new_with_specifics(
+        RefreshKind::nothing()
+            .with_cpu(CpuRefreshKind::nothing().with_frequency())
+            .with_memory(MemoryRefreshKind::nothing().with_ram()),
+    );
+    let cpu = sys.cpus().first();
+    let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+
+    EnvironmentMetadata {
+        timestamp: rfc3339_utc(now),
+        timestamp_unix: now,
+        hostname: if include_hostname { System::host_name() } else { None },
+        os: System::name(),
+        os_version: System::long_os_version().or_else(System::os_version),
+        kernel_version: System::kernel_version(),
+        arch: System::cpu_arch(),
+        cpu_model: cpu.and_then(|cpu| non_empty(cpu.brand())),
+        cpu_vendor: cpu.and_then(|cpu| non_empty(cpu.vendor_id())),
+        physical_cores: System::physical_core_count(),
+        logical_cpus: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        cpu_frequency_mhz: cpu.map(|cpu| cpu.frequency()).filter(|mhz| *mhz > 0),
+        governor: preflight::read_governor(0),
+        turbo_enabled: preflight::read_turbo_enabled(),
+        total_memory_bytes: sys.total_memory(),
+        rustc_version: env!("CRYPTO_BENCH_RUSTC_VERSION").to_string(),
+        target: env!("CRYPTO_BENCH_TARGET").to_string(),
+        profile: env!("CRYPTO_BENCH_PROFILE").to_string(),
+        opt_level: env!("CRYPTO_BENCH_OPT_LEVEL").to_string(),
+        target_features: split_list(env!("CRYPTO_BENCH_TARGET_FEATURES")).map(String::from).collect(),
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        dependencies: dependencies(),
+        features: enabled_features(),
+        rng_source: "OsRng (operating system CSPRNG)".to_string(),
+        seed: None,
+        arguments: std::env::args().collect(),
+        measurement: config.clone(),
+    }
+}
+
+fn split_list(list: &'static str) -> impl Iterator<Item = &'static str> {
+    list.split(',').filter(|item| !item.is_empty())
+}
+
+fn dependencies() -> BTreeMap<String, String> {
+    split_list(env!("CRYPTO_BENCH_DEPENDENCIES"))
+        .filter_map(|entry| entry.split_once('='))
+        .map(|(name, version)| (name.to_string(), version.to_string()))
+        .collect()
+}
+
+fn enabled_features() -> Vec<String> {
+    [("alloc-tracking", cfg!(feature = "alloc-tracking")), ("rustcrypto", cfg!(feature = "rustcrypto"))]
+        .into_iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+/// Detik sejak epoch → "YYYY-MM-DDTHH:MM:SSZ" (algoritma days-to-civil
+/// Howard Hinnant, tanpa dependency tanggal)
+fn rfc3339_utc(unix_secs: u64) -> String {
+    let days = (unix_secs / 86_400) as i64;
+    let secs_of_day = unix_secs % 86_400;
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        secs_of_day / 3_600,
+        secs_of_day % 3_600 / 60,
+        secs_of_day % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rfc3339_utc() {
+        assert_eq!(rfc3339_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(rfc3339_utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(rfc3339_utc(1_735_689_599), "2024-12-31T23:59:59Z");
+    }
+
+    #[test]
+    fn test_capture_respects_hostname_opt_out() {
+        let env = capture(&MeasurementConfig::default(), false);
+        assert!(env.hostname.is_none());
+        assert!(env.logical_cpus >= 1);
+        assert!(env.rustc_version.starts_with("rustc"));
+        assert!(env.dependencies.contains_key("sysinfo"));
+    }
+}
@@ -0,0 +1,357 @@
+// Export report ke CSV dan tabel Markdown (GitHub-flavored)
+//
+// Satu baris per metric latency (semua section, lihat
+// `BenchmarkReport::metrics`) plus satu baris per hasil throughput. Kolom dan
+// urutan baris bisa dipilih; tanpa `sort` urutan mengikuti report.
+use std::cmp::Ordering;
+use std::fmt;
+use crate::models::{BenchmarkMetric, BenchmarkReport, Size, SizeUnit, ThroughputResult};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Column {
+    Section,
+    Name,
+    Operation,
+    Implementation,
+    MessageSize,
+    Threads,
+    Mean,
+    Min,
+    Max,
+    StdDev,
+    Iterations,
+    OpsPerSec,
+    Cycles,
+    CycleUnit,
+    BytesPerSec,
+    PublicKeyBytes,
+    SignatureBytes,
+    CiphertextBytes,
+    Relative,
+    Reference,
+}
+
+impl Column {
+    pub const ALL: [Column; 20] = [
+        Column::Section,
+        Column::Name,
+        Column::Operation,
+        Column::Implementation,
+        Column::MessageSize,
+        Column::Threads,
+        Column::Mean,
+        Column::Min,
+        Column::Max,
+        Column::StdDev,
+        Column::Iterations,
+        Column::OpsPerSec,
+        Column::Cycles,
+        Column::CycleUnit,
+        Column::BytesPerSec,
+        Column::PublicKeyBytes,
+        Column::SignatureBytes,
+        Column::CiphertextBytes,
+        Column::Relative,
+        Column::Reference,
+    ];
+
+    pub const DEFAULT: [Column; 11] = [
+        Column::Section,
+        Column::Name,
+        Column::Operation,
+        Column::Implementation,
+        Column::Mean,
+        Column::StdDev,
+        Column::Min,
+        Column::Max,
+        Column::Iterations,
+        Column::OpsPerSec,
+        Column::Relative,
+    ];
+
+    /// Nama kolom untuk CLI dan header CSV
+    pub fn id(&self) -> &'static str {
+        match self {
+            Column::Section => "section",
+            Column::Name => "name",
+            Column::Operation => "operation",
+            Column::Implementation => "implementation",
+            Column::MessageSize => "message_size",
+            Column::Threads => "threads",
+            Column::Mean => "mean_us",
+            Column::Min => "min_us",
+            Column::Max => "max_us",
+            Column::StdDev => "std_dev_us",
+            Column::Iterations => "iterations",
+            Column::OpsPerSec => "ops_per_sec",
+            Column::Cycles => "cycles_per_op",
+            Column::CycleUnit => "cycle_unit",
+            Column::BytesPerSec => "bytes_per_sec",
+            Column::PublicKeyBytes => "public_key_bytes",
+            Column::SignatureBytes => "signature_bytes",
+            Column::CiphertextBytes => "ciphertext_bytes",
+            Column::Relative => "relative",
+            Column::Reference => "reference",
+        }
+    }
+
+    /// Header tabel Markdown
+    pub fn title(&self) -> &'static str {
+        match self {
+            Column::Section => "Section",
+            Column::Name => "Algorithm",
+            Column::Operation => "Operation",
+            Column::Implementation => "Implementation",
+            Column::MessageSize => "Message (B)",
+            Column::Threads => "Threads",
+            Column::Mean => "Mean (μs)",
+            Column::Min => "Min (μs)",
+            Column::Max => "Max (μs)",
+            Column::StdDev => "Std Dev (μs)",
+            Column::Iterations => "Iterations",
+            Column::OpsPerSec => "Ops/sec",
+            Column::Cycles => "Cycles/op",
+            Column::CycleUnit => "Cycle Unit",
+            Column::BytesPerSec => "Bytes/sec",
+            Column::PublicKeyBytes => "Public Key (B)",
+            Column::SignatureBytes => "Signature (B)",
+            Column::CiphertextBytes => "Ciphertext (B)",
+            Column::Relative => "× Reference",
+            Column::Reference => "Reference",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Column::ALL.into_iter().find(|column| column.id() == value)
+    }
+
+    fn is_numeric(&self) -> bool {
+        !matches!(
+            self,
+            Column::Section | Column::Name | Column::Operation | Column::Implementation | Column::CycleUnit | Column::Reference
+        )
+    }
+}
+
+/// Urutkan baris menurut satu kolom
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: Column,
+    pub descending: bool,
+}
+
+impl SortKey {
+    /// "mean_us" (naik) atau "mean_us:desc" (turun)
+    pub fn parse(value: &str) -> Option<Self> {
+        let (column, descending) = match value.split_once(':') {
+            Some((column, "asc")) => (column, false),
+            Some((column, "desc")) => (column, true),
+            Some(_) => return None,
+            None => (value, false),
+        };
+        Column::parse(column).map(|column| SortKey { column, descending })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ExportOptions {
+    pub columns: Vec<Column>,
+    pub sort: Option<SortKey>,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions { columns: Column::DEFAULT.to_vec(), sort: None }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Text(String),
+    Integer(u64),
+    Float(f64),
+    Empty,
+}
+
+impl Cell {
+    fn compare(&self, other: &Cell) -> Ordering {
+        match (self, other) {
+            (Cell::Empty, Cell::Empty) => Ordering::Equal,
+            // Sel kosong selalu di akhir
+            (Cell::Empty, _) => Ordering::Greater,
+            (_, Cell::Empty) => Ordering::Less,
+            (Cell::Text(a), Cell::Text(b)) => a.cmp(b),
+            (a, b) => a.number().total_cmp(&b.number()),
+        }
+    }
+
+    fn number(&self) -> f64 {
+        match self {
+            Cell::Integer(n) => *n as f64,
+            Cell::Float(n) => *n,
+            _ => f64::NAN,
+        }
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Cell::Text(text) => write!(f, "{}", text),
+            Cell::Integer(n) => write!(f, "{}", n),
+            Cell::Float(n) => write!(f, "{:.3}", n),
+            Cell::Empty => Ok(()),
+        }
+    }
+}
+
+enum Row<'a> {
+    Metric(&'static str, &'a BenchmarkMetric),
+    Throughput(&'a ThroughputResult),
+}
+
+impl Row<'_> {
+    fn cell(&self, column: Column) -> Cell {
+        let text = |s: &str| Cell::Text(s.to_string());
+        let bytes = |size: Option<Size>| match size {
+            Some(size) if size.unit == SizeUnit::Bytes => Cell::Integer(size.value),
+            _ => Cell::Empty,
+        };
+        match self {
+            Row::Metric(section, m) => match column {
+                Column::Section => text(section),
+                Column::Name => text(&m.name),
+                Column::Operation => text(&m.operation),
+                Column::Implementation => m.implementation.as_deref().map_or(Cell::Empty, text),
+                Column::MessageSize => m.message_size.map_or(Cell::Empty, |n| Cell::Integer(n as u64)),
+                Column::Threads => Cell::Empty,
+                Column::Mean => Cell::Float(m.mean_micros),
+                Column::Min => Cell::Float(m.min_micros),
+                Column::Max => Cell::Float(m.max_micros),
+                Column::StdDev => Cell::Float(m.std_dev_micros),
+                Column::Iterations => Cell::Integer(m.iterations as u64),
+                Column::OpsPerSec if m.mean_micros > 0.0 => Cell::Float(1_000_000.0 / m.mean_micros),
+                Column::OpsPerSec => Cell::Empty,
+                Column::Cycles => m.cycles.as_ref().map_or(Cell::Empty, |c| Cell::Float(c.mean_per_op)),
+                Column::CycleUnit => m.cycles.as_ref().map_or(Cell::Empty, |c| text(c.source.unit())),
+                Column::BytesPerSec => m.bytes_per_sec.map_or(Cell::Empty, Cell::Float),
+                Column::PublicKeyBytes => bytes(m.sizes.public_key.or(m.sizes.key)),
+                Column::SignatureBytes => bytes(m.sizes.signature),
+                Column::CiphertextBytes => bytes(m.sizes.ciphertext),
+                Column::Relative => m.relative.as_ref().map_or(Cell::Empty, |r| Cell::Float(r.factor)),
+                Column::Reference => m.relative.as_ref().map_or(Cell::Empty, |r| text(&r.reference)),
+            },
+            Row::Throughput(t) => match column {
+                Column::Section => text("throughput"),
+                Column::Name => text(&t.name),
+                Column::Operation => text(&t.operation),
+                Column::Threads => Cell::Integer(t.threads as u64),
+                Column::OpsPerSec => Cell::Float(t.ops_per_sec),
+                _ => Cell::Empty,
+            },
+        }
+    }
+}
+
+/// Baris tabel (sudah diurutkan) sebagai sel
+fn table(report: &BenchmarkReport, options: &ExportOptions) -> Vec<Vec<Cell>> {
+    let rows: Vec<Row> = report
+        .metrics()
+        .into_iter()
+        .map(|(section, metric)| Row::Metric(section, metric))
+        .chain(report.throughput.iter().map(Row::Throughput))
+        .collect();
+
+    let mut cells: Vec<(Cell, Vec<Cell>)> = rows
+        .iter()
+        .map(|row| {
+            let key = options.sort.map_or(Cell::Empty, |sort| row.cell(sort.column));
+            (key, options.columns.iter().map(|column| row.cell(*column)).collect())
+        })
+        .collect();
+    if let Some(sort) = options.sort {
+        cells.sort_by(|(a, _), (b, _)| match (a, b) {
+            (Cell::Empty, _) | (_, Cell::Empty) => a.compare(b),
+            _ if sort.descending => b.compare(a),
+            _ => a.compare(b),
+        });
+    }
+    cells.into_iter().map(|(_, row)| row).collect()
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// CSV (RFC 4180) dengan header berisi id kolom
+pub fn to_csv(report: &BenchmarkReport, options: &ExportOptions) -> String {
+    let mut out = options.columns.iter().map(|c| c.id()).collect::<Vec<_>>().join(",");
+    out.push('\n');
+    for row in table(report, options) {
+        let fields: Vec<String> = row.iter().map(|cell| csv_field(&cell.to_string())).collect();
+        out.push_str(&fields.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Tabel Markdown GitHub-flavored; kolom angka rata kanan
+pub fn to_markdown(report: &BenchmarkReport, options: &ExportOptions) -> String {
+    let line = |cells: Vec<String>| format!("| {} |\n", cells.join(" | "));
+    let mut out = line(options.columns.iter().map(|c| c.title().to_string()).collect());
+    out.push_str(&line(
+        options
+            .columns
+            .iter()
+            .map(|c| if c.is_numeric() { "---:" } else { "---" }.to_string())
+            .collect(),
+    ));
+    for row in table(report, options) {
+        out.push_str(&line(row.iter().map(|cell| cell.to_string().replace('|', "\\|")).collect()));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> BenchmarkReport {
+        BenchmarkReport {
+            signatures: vec![
+                BenchmarkMetric::for_test("Ed25519", "Sign", 20.0),
+                BenchmarkMetric::for_test("RSA-2048, PSS", "Sign", 400.0),
+                BenchmarkMetric::for_test("Falcon-512", "Sign", 150.0),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_csv_columns_sorting_and_escaping() {
+        let options = ExportOptions {
+            columns: vec![Column::Name, Column::Mean],
+            sort: SortKey::parse("mean_us:desc"),
+        };
+        assert_eq!(
+            to_csv(&report(), &options),
+            "name,mean_us\n\"RSA-2048, PSS\",400.000\nFalcon-512,150.000\nEd25519,20.000\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_table() {
+        let options = ExportOptions { columns: vec![Column::Name, Column::OpsPerSec], sort: SortKey::parse("name") };
+        let markdown = to_markdown(&report(), &options);
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(lines[0], "| Algorithm | Ops/sec |");
+        assert_eq!(lines[1], "| --- | ---: |");
+        assert_eq!(lines[2], "| Ed25519 | 50000.000 |");
+        assert_eq!(lines.len(), 5);
+        assert!(SortKey::parse("mean_us:sideways").is_none());
+    }
+}
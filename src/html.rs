@@ -0,0 +1,294 @@
+// Report HTML mandiri (satu file, tanpa server dan tanpa CDN)
+//
+// CSS dan JS inline, chart berupa SVG yang digambar di sini, dan report
+// JSON lengkap ikut disisipkan di `<script type="application/json">` supaya
+// file arsip tetap bisa dibaca ulang oleh tool lain.
+use std::fmt::Write;
+use crate::models::{BenchmarkMetric, BenchmarkReport, EnvironmentMetadata, ThroughputResult};
+
+const CHART_WIDTH: f64 = 720.0;
+const CHART_LABEL_WIDTH: f64 = 300.0;
+const CHART_VALUE_WIDTH: f64 = 110.0;
+const CHART_BAR_HEIGHT: f64 = 18.0;
+const CHART_ROW_HEIGHT: f64 = 24.0;
+
+const STYLE: &str = r#"
+body { font-family: -apple-system, "Segoe UI", Roboto, sans-serif; margin: 0; background: #f5f6f8; color: #212529; }
+header { background: #1f2937; color: #fff; padding: 24px 32px; }
+header h1 { margin: 0 0 4px; font-size: 24px; }
+header p { margin: 0; color: #cbd5e1; }
+main { padding: 24px 32px; max-width: 1200px; }
+section { background: #fff; border-radius: 8px; padding: 16px 24px; margin-bottom: 24px; box-shadow: 0 1px 3px rgba(0,0,0,.1); }
+h2 { font-size: 18px; margin-top: 0; }
+table { border-collapse: collapse; width: 100%; font-size: 14px; }
+th, td { padding: 6px 10px; border-bottom: 1px solid #e5e7eb; text-align: left; }
+th { background: #f9fafb; cursor: pointer; user-select: none; }
+td.num, th.num { text-align: right; font-variant-numeric: tabular-nums; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 4px 16px; margin: 0; font-size: 14px; }
+dt { color: #6b7280; }
+dd { margin: 0; }
+.warning { color: #b45309; }
+svg text { font-size: 12px; fill: #374151; }
+svg rect.bar { fill: #3b82f6; }
+"#;
+
+/// Klik header tabel untuk mengurutkan (angka dibandingkan sebagai angka)
+const SCRIPT: &str = r#"
+document.querySelectorAll('table.sortable th').forEach((th, index) => {
+  th.addEventListener('click', () => {
+    const tbody = th.closest('table').querySelector('tbody');
+    const descending = th.dataset.order !== 'desc';
+    th.dataset.order = descending ? 'desc' : 'asc';
+    const value = row => row.children[index].dataset.value ?? row.children[index].textContent;
+    const rows = Array.from(tbody.rows).sort((a, b) => {
+      const [x, y] = [value(a), value(b)];
+      const numeric = x !== '' && y !== '' && !isNaN(x) && !isNaN(y);
+      const order = numeric ? Number(x) - Number(y) : x.localeCompare(y);
+      return descending ? -order : order;
+    });
+    rows.forEach(row => tbody.appendChild(row));
+  });
+});
+"#;
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+fn section_title(section: &str) -> &str {
+    match section {
+        "signatures" => "Digital Signatures",
+        "kem" => "Key Exchange / KEM",
+        "keygen" => "Key Generation",
+        "message_sweep" => "Message Size Sweep",
+        "serialization" => "Encoding / Decoding",
+        "implementations" => "Implementations",
+        "cache_warm" => "Warm Cache",
+        "cache_cold" => "Cold Cache",
+        "key_pool" => "Key Pool",
+        "batch_verify" => "Batch Verification",
+        other => other,
+    }
+}
+
+fn metric_label(metric: &BenchmarkMetric) -> String {
+    let mut label = format!("{} {}", metric.name, metric.operation);
+    if let Some(implementation) = &metric.implementation {
+        label.push_str(&format!(" [{}]", implementation));
+    }
+    if let Some(size) = metric.message_size {
+        label.push_str(&format!(" @ {} B", size));
+    }
+    label
+}
+
+/// Bar chart horizontal SVG; panjang bar linear terhadap nilai terbesar
+fn bar_chart(bars: &[(String, f64)], unit: &str) -> String {
+    let max = bars.iter().map(|(_, value)| *value).fold(0.0, f64::max);
+    let bar_space = CHART_WIDTH - CHART_LABEL_WIDTH - CHART_VALUE_WIDTH;
+    let height = bars.len() as f64 * CHART_ROW_HEIGHT;
+    let mut svg = format!(
+        r#"<svg role="img" width="{w}" height="{h}" viewBox="0 0 {w} {h}" xmlns="http://www.w3.org/2000/svg">"#,
+        w = CHART_WIDTH,
+        h = height
+    );
+    for (i, (label, value)) in bars.iter().enumerate() {
+        let y = i as f64 * CHART_ROW_HEIGHT;
+        let width = if max > 0.0 { (value / max * bar_space).max(1.0) } else { 1.0 };
+        let _ = write!(
+            svg,
+            r#"<text x="{lx}" y="{ty}" text-anchor="end">{label}</text><rect class="bar" x="{bx}" y="{y}" width="{width:.1}" height="{bh}"><title>{label}: {value:.3} {unit}</title></rect><text x="{vx:.1}" y="{ty}">{value:.2} {unit}</text>"#,
+            lx = CHART_LABEL_WIDTH - 8.0,
+            ty = y + CHART_BAR_HEIGHT - 5.0,
+            label = escape(label),
+            bx = CHART_LABEL_WIDTH,
+            bh = CHART_BAR_HEIGHT,
+            vx = CHART_LABEL_WIDTH + width + 6.0,
+            unit = unit,
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+fn numeric_cell(value: f64, decimals: usize) -> String {
+    format!(r#"<td class="num" data-value="{}">{:.*}</td>"#, value, decimals, value)
+}
+
+fn metric_section(out: &mut String, section: &str, metrics: &[&BenchmarkMetric]) {
+    let _ = write!(out, "<section><h2>{}</h2>", escape(section_title(section)));
+    let bars: Vec<(String, f64)> = metrics.iter().map(|m| (metric_label(m), m.mean_micros)).collect();
+    out.push_str(&bar_chart(&bars, "μs"));
+    out.push_str(
+        r#"<table class="sortable"><thead><tr><th>Algorithm</th><th>Operation</th><th>Implementation</th><th class="num">Message (B)</th><th class="num">Mean (μs)</th><th class="num">Std Dev (μs)</th><th class="num">Min (μs)</th><th class="num">Max (μs)</th><th class="num">Ops/sec</th><th class="num">× Reference</th><th class="num">Samples</th><th>Sizes</th></tr></thead><tbody>"#,
+    );
+    for m in metrics {
+        let sizes: Vec<String> = m.sizes.entries().iter().map(|(label, size)| format!("{}: {}", label, size)).collect();
+        let ops_per_sec = if m.mean_micros > 0.0 { 1_000_000.0 / m.mean_micros } else { 0.0 };
+        let relative = m.relative.as_ref().map_or_else(
+            || r#"<td class="num"></td>"#.to_string(),
+            |r| {
+                format!(
+                    r#"<td class="num" data-value="{}" title="vs {} {}">{:.2}× {}</td>"#,
+                    r.factor,
+                    escape(&r.reference),
+                    escape(&r.reference_operation),
+                    r.factor,
+                    escape(&r.reference)
+                )
+            },
+        );
+        let _ = write!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td class=\"num\">{}</td>{}{}{}{}{}{}<td class=\"num\">{}</td><td>{}</td></tr>",
+            escape(&m.name),
+            escape(&m.operation),
+            escape(m.implementation.as_deref().unwrap_or("")),
+            m.message_size.map_or(String::new(), |size| size.to_string()),
+            numeric_cell(m.mean_micros, 3),
+            numeric_cell(m.std_dev_micros, 3),
+            numeric_cell(m.min_micros, 3),
+            numeric_cell(m.max_micros, 3),
+            numeric_cell(ops_per_sec, 0),
+            relative,
+            m.iterations,
+            escape(&sizes.join(", ")),
+        );
+    }
+    out.push_str("</tbody></table></section>");
+}
+
+fn throughput_section(out: &mut String, results: &[ThroughputResult]) {
+    out.push_str("<section><h2>Throughput</h2>");
+    let bars: Vec<(String, f64)> = results
+        .iter()
+        .map(|r| (format!("{} {} × {}", r.name, r.operation, r.threads), r.ops_per_sec))
+        .collect();
+    out.push_str(&bar_chart(&bars, "ops/s"));
+    out.push_str(
+        r#"<table class="sortable"><thead><tr><th>Algorithm</th><th>Operation</th><th class="num">Threads</th><th class="num">Ops/sec</th><th class="num">Per thread</th><th class="num">Scaling</th></tr></thead><tbody>"#,
+    );
+    for r in results {
+        let _ = write!(
+            out,
+            "<tr><td>{}</td><td>{}</td><td class=\"num\">{}</td>{}{}{}</tr>",
+            escape(&r.name),
+            escape(&r.operation),
+            r.threads,
+            numeric_cell(r.ops_per_sec, 0),
+            numeric_cell(r.ops_per_sec_per_thread, 0),
+            numeric_cell(r.scaling_efficiency, 2),
+        );
+    }
+    out.push_str("</tbody></table></section>");
+}
+
+fn environment_section(out: &mut String, env: &EnvironmentMetadata) {
+    let mut rows: Vec<(&str, String)> = vec![("Timestamp", env.timestamp.clone())];
+    if let Some(host) = &env.hostname {
+        rows.push(("Host", host.clone()));
+    }
+    let os = [env.os_version.as_deref().or(env.os.as_deref()), env.kernel_version.as_deref()];
+    rows.push(("OS", os.iter().flatten().copied().collect::<Vec<_>>().join(", kernel ")));
+    let cores = env.physical_cores.map_or(String::new(), |n| format!("{} cores / ", n));
+    let freq = env.cpu_frequency_mhz.map_or(String::new(), |mhz| format!(" @ {} MHz", mhz));
+    rows.push((
+        "CPU",
+        format!("{} ({}{} threads){}", env.cpu_model.as_deref().unwrap_or("unknown"), cores, env.logical_cpus, freq),
+    ));
+    rows.push(("Memory", format!("{:.1} GiB", env.total_memory_bytes as f64 / (1u64 << 30) as f64)));
+    rows.push(("Compiler", env.rustc_version.clone()));
+    rows.push(("Target", format!("{} ({}, opt-level {})", env.target, env.profile, env.opt_level)));
+    rows.push(("crypto_benchmark", env.crate_version.clone()));
+    rows.push(("Command", env.arguments.join(" ")));
+
+    out.push_str("<section><h2>Environment</h2><dl>");
+    for (label, value) in rows {
+        let _ = write!(out, "<dt>{}</dt><dd>{}</dd>", escape(label), escape(&value));
+    }
+    out.push_str("</dl></section>");
+}
+
+/// Render report menjadi satu dokumen HTML yang bisa dibuka offline
+pub fn render(report: &BenchmarkReport) -> String {
+    let mut body = String::new();
+
+    if let Some(env) = &report.environment {
+        environment_section(&mut body, env);
+    }
+    if let Some(preflight) = report.preflight.as_ref().filter(|p| !p.warnings.is_empty()) {
+        body.push_str("<section><h2>Pre-flight warnings</h2><ul>");
+        for warning in &preflight.warnings {
+            let _ = write!(body, "<li class=\"warning\">{}</li>", escape(warning));
+        }
+        body.push_str("</ul></section>");
+    }
+
+    // Kelompokkan per section, urutan section mengikuti report
+    let mut sections: Vec<(&str, Vec<&BenchmarkMetric>)> = Vec::new();
+    for (section, metric) in report.metrics() {
+        match sections.iter_mut().find(|(name, _)| *name == section) {
+            Some((_, metrics)) => metrics.push(metric),
+            None => sections.push((section, vec![metric])),
+        }
+    }
+    for (section, metrics) in &sections {
+        metric_section(&mut body, section, metrics);
+    }
+    if !report.throughput.is_empty() {
+        throughput_section(&mut body, &report.throughput);
+    }
+
+    // `<` di-escape (tetap JSON valid) supaya tidak ada tag di dalam <script>
+    let data = serde_json::to_string(report).unwrap_or_default().replace('<', "\\u003c");
+    let subtitle = report.environment.as_ref().map_or(String::new(), |env| format!("Run at {}", env.timestamp));
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<title>Crypto Benchmark Report</title>
+<style>{style}</style>
+</head>
+<body>
+<header><h1>🔐 Crypto Benchmark Report</h1><p>{subtitle} · total {total:.2}s, key generation {keygen:.2}s</p></header>
+<main>
+{body}
+</main>
+<script type="application/json" id="report-data">{data}</script>
+<script>{script}</script>
+</body>
+</html>
+"#,
+        style = STYLE,
+        subtitle = escape(&subtitle),
+        total = report.total_time_secs,
+        keygen = report.keygen_time_secs,
+        body = body,
+        data = data,
+        script = SCRIPT,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_is_self_contained_and_escaped() {
+        let metric = BenchmarkMetric::for_test("<script>alert(1)</script>", "Sign", 20.0);
+        let report = BenchmarkReport { signatures: vec![metric], ..Default::default() };
+        let html = render(&report);
+
+        assert!(html.contains("<h2>Digital Signatures</h2>"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(!html.contains("<script>alert(1)"));
+        // Tidak ada resource eksternal (namespace SVG bukan request)
+        assert!(!html.contains("src=\"http") && !html.contains("href=\"http"));
+    }
+}
@@ -0,0 +1,248 @@
+// Perbandingan beberapa implementasi untuk algoritma yang sama
+//
+// Default hanya `pqcrypto` (wrapper C dari PQClean). Dengan feature
+// `rustcrypto`, crate pure-Rust `ml-kem` dan `ml-dsa` ikut diukur dengan
+// nama algoritma yang sama, dibedakan lewat field `implementation`.
+// Cross-check memastikan output keduanya saling interoperable.
+use pqcrypto_mldsa::mldsa44;
+use pqcrypto_mlkem::mlkem512;
+use crate::constants::DEFAULT_MESSAGE;
+use crate::measurement::{benchmark_operation_with, BenchmarkResult, MeasurementConfig};
+use crate::models::{BenchmarkMetric, InteropCheck};
+
+pub const IMPL_PQCRYPTO: &str = "pqcrypto";
+pub const IMPL_RUSTCRYPTO: &str = "RustCrypto";
+
+pub const KYBER_NAME: &str = "Kyber (ML-KEM-512)";
+pub const DILITHIUM_NAME: &str = "Dilithium (ML-DSA-44)";
+
+const BENCH_ITERATIONS: usize = 50;
+
+/// Implementasi yang tersedia di build ini
+pub fn available_implementations() -> Vec<&'static str> {
+    let mut implementations = vec![IMPL_PQCRYPTO];
+    if cfg!(feature = "rustcrypto") {
+        implementations.push(IMPL_RUSTCRYPTO);
+    }
+    implementations
+}
+
+fn metric(name: &str, operation: &str, implementation: &str, result: BenchmarkResult) -> BenchmarkMetric {
+    result
+        .to_metric(name.to_string(), operation.to_string(), Default::default())
+        .with_implementation(implementation)
+}
+
+/// ML-KEM-512 dan ML-DSA-44 untuk setiap implementasi yang tersedia, hanya
+/// algoritma yang lolos `config.algorithms`
+pub fn benchmark_implementations(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let mut metrics = benchmark_pqcrypto(config);
+    metrics.extend(rustcrypto::benchmark(config));
+    metrics
+}
+
+fn benchmark_pqcrypto(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let message = DEFAULT_MESSAGE;
+    let mut metrics = Vec::new();
+
+    if config.includes(KYBER_NAME) {
+        let (kyber_public, kyber_secret) = mlkem512::keypair();
+        let (_, ciphertext) = mlkem512::encapsulate(&kyber_public);
+        metrics.extend([
+            metric(KYBER_NAME, "Keygen", IMPL_PQCRYPTO, benchmark_operation_with(mlkem512::keypair, BENCH_ITERATIONS, config)),
+            metric(KYBER_NAME, "Encapsulate", IMPL_PQCRYPTO, benchmark_operation_with(
+                || mlkem512::encapsulate(&kyber_public),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            metric(KYBER_NAME, "Decapsulate", IMPL_PQCRYPTO, benchmark_operation_with(
+                || mlkem512::decapsulate(&ciphertext, &kyber_secret),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+
+    if config.includes(DILITHIUM_NAME) {
+        let (dilithium_public, dilithium_secret) = mldsa44::keypair();
+        let signature = mldsa44::detached_sign(message, &dilithium_secret);
+        metrics.extend([
+            metric(DILITHIUM_NAME, "Keygen", IMPL_PQCRYPTO, benchmark_operation_with(mldsa44::keypair, BENCH_ITERATIONS, config)),
+            metric(DILITHIUM_NAME, "Sign", IMPL_PQCRYPTO, benchmark_operation_with(
+                || mldsa44::detached_sign(message, &dilithium_secret),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            metric(DILITHIUM_NAME, "Verify", IMPL_PQCRYPTO, benchmark_operation_with(
+                || {
+                    mldsa44::verify_detached_signature(&signature, message, &dilithium_public)
+                        .expect("Dilithium verification failed")
+                },
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+    metrics
+}
+
+/// Cross-check antar implementasi untuk algoritma yang lolos `config.algorithms`.
+/// Kosong jika hanya satu implementasi tersedia.
+pub fn cross_check(config: &MeasurementConfig) -> Vec<InteropCheck> {
+    rustcrypto::cross_check(config)
+}
+
+#[cfg(not(feature = "rustcrypto"))]
+mod rustcrypto {
+    use super::*;
+
+    pub fn benchmark(_config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+        Vec::new()
+    }
+
+    pub fn cross_check(_config: &MeasurementConfig) -> Vec<InteropCheck> {
+        Vec::new()
+    }
+}
+
+#[cfg(feature = "rustcrypto")]
+mod rustcrypto {
+    use ml_dsa::signature::{Signer as _, Verifier as _};
+    use ml_dsa::{KeyGen, MlDsa44};
+    use ml_kem::kem::{Decapsulate, Encapsulate};
+    use ml_kem::{EncodedSizeUser, KemCore, MlKem512};
+    use pqcrypto_traits::kem::{Ciphertext as _, PublicKey as _, SharedSecret as _};
+    use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _};
+    use rand::rngs::OsRng;
+    use super::*;
+
+    type EncapsulationKey = <MlKem512 as KemCore>::EncapsulationKey;
+    type DecapsulationKey = <MlKem512 as KemCore>::DecapsulationKey;
+
+    pub fn benchmark(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+        let message = DEFAULT_MESSAGE;
+        let mut metrics = Vec::new();
+
+        if config.includes(KYBER_NAME) {
+            let (decapsulation_key, encapsulation_key) = MlKem512::generate(&mut OsRng);
+            let (ciphertext, _) = encapsulation_key.encapsulate(&mut OsRng).expect("ML-KEM encapsulation failed");
+            metrics.extend([
+                metric(KYBER_NAME, "Keygen", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || MlKem512::generate(&mut OsRng),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+                metric(KYBER_NAME, "Encapsulate", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || encapsulation_key.encapsulate(&mut OsRng).expect("ML-KEM encapsulation failed"),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+                metric(KYBER_NAME, "Decapsulate", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || decapsulation_key.decapsulate(&ciphertext).expect("ML-KEM decapsulation failed"),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+            ]);
+        }
+
+        if config.includes(DILITHIUM_NAME) {
+            let keypair = MlDsa44::key_gen(&mut OsRng);
+            let signature = keypair.signing_key().sign(message);
+            metrics.extend([
+                metric(DILITHIUM_NAME, "Keygen", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || MlDsa44::key_gen(&mut OsRng),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+                metric(DILITHIUM_NAME, "Sign", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || keypair.signing_key().sign(message),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+                metric(DILITHIUM_NAME, "Verify", IMPL_RUSTCRYPTO, benchmark_operation_with(
+                    || keypair.verifying_key().verify(message, &signature).expect("Dilithium verification failed"),
+                    BENCH_ITERATIONS,
+                    config,
+                )),
+            ]);
+        }
+        metrics
+    }
+
+    fn check(algorithm: &str, direction: &str, outcome: Result<(), String>) -> InteropCheck {
+        InteropCheck {
+            algorithm: algorithm.to_string(),
+            direction: direction.to_string(),
+            passed: outcome.is_ok(),
+            detail: outcome.err(),
+        }
+    }
+
+    pub fn cross_check(config: &MeasurementConfig) -> Vec<InteropCheck> {
+        let mut checks = Vec::new();
+        if config.includes(KYBER_NAME) {
+            checks.push(check(KYBER_NAME, "pqcrypto keygen → RustCrypto encapsulate → pqcrypto decapsulate", kem_pqcrypto_to_rustcrypto()));
+            checks.push(check(KYBER_NAME, "RustCrypto keygen → pqcrypto encapsulate → RustCrypto decapsulate", kem_rustcrypto_to_pqcrypto()));
+        }
+        if config.includes(DILITHIUM_NAME) {
+            checks.push(check(DILITHIUM_NAME, "pqcrypto sign → RustCrypto verify", sign_pqcrypto_to_rustcrypto()));
+            checks.push(check(DILITHIUM_NAME, "RustCrypto sign → pqcrypto verify", sign_rustcrypto_to_pqcrypto()));
+        }
+        checks
+    }
+
+    fn kem_pqcrypto_to_rustcrypto() -> Result<(), String> {
+        let (public, secret) = mlkem512::keypair();
+        let encoded = public.as_bytes().try_into().map_err(|_| "public key length mismatch".to_string())?;
+        let encapsulation_key = EncapsulationKey::from_bytes(encoded);
+        let (ciphertext, shared) = encapsulation_key
+            .encapsulate(&mut OsRng)
+            .map_err(|_| "RustCrypto encapsulation failed".to_string())?;
+
+        let ciphertext = mlkem512::Ciphertext::from_bytes(ciphertext.as_slice()).map_err(|e| e.to_string())?;
+        let expected = mlkem512::decapsulate(&ciphertext, &secret);
+        if expected.as_bytes() == shared.as_slice() {
+            Ok(())
+        } else {
+            Err("shared secrets differ".to_string())
+        }
+    }
+
+    fn kem_rustcrypto_to_pqcrypto() -> Result<(), String> {
+        let (decapsulation_key, encapsulation_key): (DecapsulationKey, EncapsulationKey) = MlKem512::generate(&mut OsRng);
+        let public = mlkem512::PublicKey::from_bytes(encapsulation_key.as_bytes().as_slice()).map_err(|e| e.to_string())?;
+        let (shared, ciphertext) = mlkem512::encapsulate(&public);
+
+        let ciphertext: &ml_kem::Ciphertext<MlKem512> =
+            ciphertext.as_bytes().try_into().map_err(|_| "ciphertext length mismatch".to_string())?;
+        let expected = decapsulation_key
+            .decapsulate(ciphertext)
+            .map_err(|_| "RustCrypto decapsulation failed".to_string())?;
+        if expected.as_slice() == shared.as_bytes() {
+            Ok(())
+        } else {
+            Err("shared secrets differ".to_string())
+        }
+    }
+
+    fn sign_pqcrypto_to_rustcrypto() -> Result<(), String> {
+        let (public, secret) = mldsa44::keypair();
+        let signature = mldsa44::detached_sign(DEFAULT_MESSAGE, &secret);
+
+        let encoded_key = public.as_bytes().try_into().map_err(|_| "public key length mismatch".to_string())?;
+        let verifying_key = ml_dsa::VerifyingKey::<MlDsa44>::decode(encoded_key);
+        let encoded_signature = signature.as_bytes().try_into().map_err(|_| "signature length mismatch".to_string())?;
+        let signature = ml_dsa::Signature::<MlDsa44>::decode(encoded_signature)
+            .ok_or_else(|| "malformed signature".to_string())?;
+        verifying_key.verify(DEFAULT_MESSAGE, &signature).map_err(|e| e.to_string())
+    }
+
+    fn sign_rustcrypto_to_pqcrypto() -> Result<(), String> {
+        let keypair = MlDsa44::key_gen(&mut OsRng);
+        let signature = keypair.signing_key().sign(DEFAULT_MESSAGE);
+
+        let public = mldsa44::PublicKey::from_bytes(keypair.verifying_key().encode().as_slice()).map_err(|e| e.to_string())?;
+        let signature = mldsa44::DetachedSignature::from_bytes(signature.encode().as_slice()).map_err(|e| e.to_string())?;
+        mldsa44::verify_detached_signature(&signature, DEFAULT_MESSAGE, &public).map_err(|e| e.to_string())
+    }
+}
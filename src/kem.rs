@@ -1,7 +1,9 @@
 // Optimized KEM module dengan pre-generated keys
 use crate::keys::BenchmarkKeys;
-use crate::measurement::benchmark_operation;
-use crate::models::BenchmarkMetric;
+use crate::measurement::{benchmark_operation_with, MeasurementConfig};
+use crate::models::{ArtifactSizes, BenchmarkMetric, ResourceUsage, Size};
+use crate::implementations::IMPL_PQCRYPTO;
+use crate::resources::sample_algorithm;
 use std::collections::HashMap;
 use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey};
 use p256::ecdh::EphemeralSecret as P256EphemeralSecret;
@@ -11,21 +13,38 @@ use rand::rngs::OsRng;
 const BENCH_ITERATIONS: usize = 100;
 
 pub fn benchmark_kem_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
-    let mut metrics = Vec::new();
-
-    // Diffie-Hellman (X25519)
-    metrics.extend(benchmark_dh_optimized());
+    benchmark_kem_with_config(keys, &MeasurementConfig::default())
+}
 
-    // ECDH (P-256)
-    metrics.extend(benchmark_ecdh_optimized());
+pub fn benchmark_kem_with_config(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    benchmark_kem_with_resources(keys, config).0
+}
 
-    // Kyber (ML-KEM)
-    metrics.extend(benchmark_kyber_optimized(keys));
+/// Jalankan semua KEM benchmark dan catat CPU/RSS proses per algoritma
+pub fn benchmark_kem_with_resources(
+    keys: &BenchmarkKeys,
+    config: &MeasurementConfig,
+) -> (Vec<BenchmarkMetric>, Vec<ResourceUsage>) {
+    let runs: [(&str, &dyn Fn() -> Vec<BenchmarkMetric>); 3] = [
+        ("X25519 Diffie-Hellman", &|| benchmark_dh_optimized(config)),
+        ("ECDH (P-256)", &|| benchmark_ecdh_optimized(config)),
+        ("Kyber (ML-KEM-512)", &|| benchmark_kyber_optimized(keys, config)),
+    ];
 
-    metrics
+    let mut metrics = Vec::new();
+    let mut resources = Vec::new();
+    for (name, run) in runs {
+        if !config.includes(name) {
+            continue;
+        }
+        let (algorithm_metrics, usage) = sample_algorithm(config, run);
+        metrics.extend(algorithm_metrics);
+        resources.extend(usage);
+    }
+    (metrics, resources)
 }
 
-fn benchmark_dh_optimized() -> Vec<BenchmarkMetric> {
+fn benchmark_dh_optimized(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     // Pre-generate keypairs
     let alice_secret = EphemeralSecret::random_from_rng(OsRng);
     let alice_public = X25519PublicKey::from(&alice_secret);
@@ -33,12 +52,10 @@ fn benchmark_dh_optimized() -> Vec<BenchmarkMetric> {
     let bob_public = X25519PublicKey::from(&bob_secret);
     
     // Benchmark key exchange (Alice's side)
-    let exchange_result = benchmark_operation(
-        || {
-            let temp_secret = EphemeralSecret::random_from_rng(OsRng);
-            let _shared = temp_secret.diffie_hellman(&bob_public);
-        },
-        BENCH_ITERATIONS
+    let exchange_result = benchmark_operation_with(
+        || exchange_x25519(&bob_public),
+        BENCH_ITERATIONS,
+        config,
     );
     
     // Verify correctness
@@ -46,16 +63,18 @@ fn benchmark_dh_optimized() -> Vec<BenchmarkMetric> {
     let bob_shared = bob_secret.diffie_hellman(&alice_public);
     assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes(), "DH shared secret mismatch");
     
-    let mut info = HashMap::new();
-    info.insert("key_size".to_string(), "32 bytes".to_string());
-    info.insert("shared_secret_size".to_string(), format!("{} bytes", alice_shared.as_bytes().len()));
+    let sizes = ArtifactSizes {
+        key: Some(Size::bytes(32)),
+        shared_secret: Some(Size::bytes(alice_shared.as_bytes().len())),
+        ..Default::default()
+    };
 
     vec![
-        exchange_result.to_metric("X25519 Diffie-Hellman".to_string(), "Key Exchange".to_string(), info)
+        exchange_result.to_metric("X25519 Diffie-Hellman".to_string(), "Key Exchange".to_string(), HashMap::new()).with_implementation("x25519-dalek").with_sizes(sizes)
     ]
 }
 
-fn benchmark_ecdh_optimized() -> Vec<BenchmarkMetric> {
+fn benchmark_ecdh_optimized(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     // Pre-generate keypairs
     let alice_secret = P256EphemeralSecret::random(&mut OsRng);
     let alice_public = alice_secret.public_key();
@@ -63,12 +82,10 @@ fn benchmark_ecdh_optimized() -> Vec<BenchmarkMetric> {
     let bob_public = bob_secret.public_key();
     
     // Benchmark key exchange
-    let exchange_result = benchmark_operation(
-        || {
-            let temp_secret = P256EphemeralSecret::random(&mut OsRng);
-            let _shared = temp_secret.diffie_hellman(&bob_public);
-        },
-        BENCH_ITERATIONS
+    let exchange_result = benchmark_operation_with(
+        || exchange_ecdh(&bob_public),
+        BENCH_ITERATIONS,
+        config,
     );
     
     // Verify correctness
@@ -80,49 +97,71 @@ fn benchmark_ecdh_optimized() -> Vec<BenchmarkMetric> {
         "ECDH shared secret mismatch"
     );
     
-    let mut info = HashMap::new();
-    info.insert("key_size".to_string(), "32 bytes".to_string());
-    info.insert("shared_secret_size".to_string(), format!("{} bytes", alice_shared.raw_secret_bytes().len()));
+    let sizes = ArtifactSizes {
+        key: Some(Size::bytes(32)),
+        shared_secret: Some(Size::bytes(alice_shared.raw_secret_bytes().len())),
+        ..Default::default()
+    };
 
     vec![
-        exchange_result.to_metric("ECDH (P-256)".to_string(), "Key Exchange".to_string(), info)
+        exchange_result.to_metric("ECDH (P-256)".to_string(), "Key Exchange".to_string(), HashMap::new()).with_implementation("p256").with_sizes(sizes)
     ]
 }
 
-fn benchmark_kyber_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_kyber_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     // Benchmark encapsulation
-    let encaps_result = benchmark_operation(
-        || {
-            let (_shared_secret, _ciphertext) = mlkem512::encapsulate(&keys.kyber_public);
-        },
-        50  // PQC is slower
+    let encaps_result = benchmark_operation_with(
+        || encapsulate_kyber(keys),
+        50,  // PQC is slower
+        config,
     );
     
     // Pre-generate ciphertext for decapsulation benchmark
-    let (_shared_secret_alice, ciphertext) = mlkem512::encapsulate(&keys.kyber_public);
+    let (_shared_secret_alice, ciphertext) = encapsulate_kyber(keys);
     
     // Benchmark decapsulation
-    let decaps_result = benchmark_operation(
-        || {
-            let _shared_secret_bob = mlkem512::decapsulate(&ciphertext, &keys.kyber_secret);
-        },
-        BENCH_ITERATIONS
+    let decaps_result = benchmark_operation_with(
+        || decapsulate_kyber(keys, &ciphertext),
+        BENCH_ITERATIONS,
+        config,
     );
     
     // Note: Kyber shared secrets are opaque types, we can't directly compare
     // but the fact that decapsulation completes without error validates correctness
     
-    let mut info = HashMap::new();
-    info.insert("public_key_size".to_string(), format!("{} bytes", mlkem512::public_key_bytes()));
-    info.insert("ciphertext_size".to_string(), format!("{} bytes", mlkem512::ciphertext_bytes()));
-    info.insert("shared_secret_size".to_string(), format!("{} bytes", mlkem512::shared_secret_bytes()));
+    let sizes = ArtifactSizes {
+        public_key: Some(Size::bytes(mlkem512::public_key_bytes())),
+        ciphertext: Some(Size::bytes(mlkem512::ciphertext_bytes())),
+        shared_secret: Some(Size::bytes(mlkem512::shared_secret_bytes())),
+        ..Default::default()
+    };
 
     vec![
-        encaps_result.to_metric("Kyber (ML-KEM-512)".to_string(), "Encapsulate".to_string(), info.clone()),
-        decaps_result.to_metric("Kyber (ML-KEM-512)".to_string(), "Decapsulate".to_string(), info)
+        encaps_result.to_metric("Kyber (ML-KEM-512)".to_string(), "Encapsulate".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes.clone()),
+        decaps_result.to_metric("Kyber (ML-KEM-512)".to_string(), "Decapsulate".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes)
     ]
 }
 
+// Operasi per algoritma, dipakai suite utama dan `throughput` supaya keduanya
+// mengukur panggilan yang sama. Key exchange memakai secret ephemeral baru
+// terhadap public key peer yang tetap.
+
+pub(crate) fn exchange_x25519(peer: &X25519PublicKey) -> x25519_dalek::SharedSecret {
+    EphemeralSecret::random_from_rng(OsRng).diffie_hellman(peer)
+}
+
+pub(crate) fn exchange_ecdh(peer: &p256::PublicKey) -> p256::ecdh::SharedSecret {
+    P256EphemeralSecret::random(&mut OsRng).diffie_hellman(peer)
+}
+
+pub(crate) fn encapsulate_kyber(keys: &BenchmarkKeys) -> (mlkem512::SharedSecret, mlkem512::Ciphertext) {
+    mlkem512::encapsulate(&keys.kyber_public)
+}
+
+pub(crate) fn decapsulate_kyber(keys: &BenchmarkKeys, ciphertext: &mlkem512::Ciphertext) -> mlkem512::SharedSecret {
+    mlkem512::decapsulate(ciphertext, &keys.kyber_secret)
+}
+
 // Helper functions for Criterion benchmarks
 use rsa::{RsaPrivateKey, RsaPublicKey, Oaep};
 use sha2::Sha256;
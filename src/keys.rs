@@ -7,8 +7,19 @@ use pqcrypto_falcon::falcon512;
 use pqcrypto_mlkem::mlkem512;
 use rand::rngs::OsRng;
 use rand::RngCore;
+use ed25519_dalek::{Signature as Ed25519Signature, Signer};
+use p256::ecdsa::Signature as P256Signature;
+use rsa::Pss;
+use sha2::{Digest, Sha256};
+use x25519_dalek::PublicKey as X25519PublicKey;
 use crate::error::{BenchmarkError, Result};
-use crate::constants::RSA_KEY_SIZE;
+use std::collections::HashMap;
+use crate::constants::{DEFAULT_MESSAGE, RSA_KEY_SIZE};
+use crate::measurement::{benchmark_operation_with, BenchmarkResult, MeasurementConfig};
+use crate::models::BenchmarkMetric;
+
+const KEYGEN_ITERATIONS: usize = 50;
+const RSA_KEYGEN_ITERATIONS: usize = 10;
 
 /// Pre-generated keys untuk semua algoritma
 /// Ini menghemat 70-80% waktu benchmark dengan menghindari regenerasi key
@@ -35,7 +46,7 @@ pub struct BenchmarkKeys {
 impl BenchmarkKeys {
     /// Generate semua keys sekali. Operasi ini lambat (~500ms) tapi hanya dilakukan sekali.
     pub fn generate() -> Result<Self> {
-        println!("Generating benchmark keys (this may take a moment)...");
+        eprintln!("Generating benchmark keys (this may take a moment)...");
         
         // Ed25519 keys
         let mut secret_bytes = [0u8; 32];
@@ -62,7 +73,7 @@ impl BenchmarkKeys {
         // Kyber (ML-KEM) keys
         let (kyber_public, kyber_secret) = mlkem512::keypair();
         
-        println!("✓ All keys generated successfully");
+        eprintln!("✓ All keys generated successfully");
         
         Ok(BenchmarkKeys {
             ed25519_signing,
@@ -86,6 +97,171 @@ impl Drop for BenchmarkKeys {
         // Security: Zeroize sensitive key material
         // Note: Ideally kita akan gunakan zeroize crate untuk ini
         // Tapi untuk sekarang, ini menunjukkan intent
-        println!("Cleaning up benchmark keys...");
+        eprintln!("Cleaning up benchmark keys...");
+    }
+}
+
+/// Satu set key, pesan dan signature yang independen untuk setiap algoritma.
+/// Dipakai untuk merotasi input antar sample (cold-cache mode).
+pub struct KeySet {
+    pub message: Vec<u8>,
+
+    pub ed25519_signing: Ed25519SigningKey,
+    pub ed25519_verifying: Ed25519VerifyingKey,
+    pub ed25519_signature: Ed25519Signature,
+    pub rsa_private: RsaPrivateKey,
+    pub rsa_public: RsaPublicKey,
+    pub rsa_signature: Vec<u8>,
+    pub ecdsa_signing: P256SigningKey,
+    pub ecdsa_verifying: P256VerifyingKey,
+    pub ecdsa_signature: P256Signature,
+
+    pub dilithium_public: mldsa44::PublicKey,
+    pub dilithium_secret: mldsa44::SecretKey,
+    pub dilithium_signature: mldsa44::DetachedSignature,
+    pub falcon_public: falcon512::PublicKey,
+    pub falcon_secret: falcon512::SecretKey,
+    pub falcon_signature: falcon512::DetachedSignature,
+
+    pub x25519_public: X25519PublicKey,
+    pub p256_public: p256::PublicKey,
+    pub kyber_public: mlkem512::PublicKey,
+    pub kyber_secret: mlkem512::SecretKey,
+    pub kyber_ciphertext: mlkem512::Ciphertext,
+}
+
+impl KeySet {
+    pub fn generate() -> Result<Self> {
+        let mut rng = OsRng;
+        let mut message = vec![0u8; DEFAULT_MESSAGE.len()];
+        rng.fill_bytes(&mut message);
+
+        let mut secret_bytes = [0u8; 32];
+        rng.fill_bytes(&mut secret_bytes);
+        let ed25519_signing = Ed25519SigningKey::from_bytes(&secret_bytes);
+        let ed25519_signature = ed25519_signing.sign(&message);
+
+        let rsa_private = RsaPrivateKey::new(&mut rng, RSA_KEY_SIZE)
+            .map_err(|e| BenchmarkError::KeyGeneration(format!("RSA: {}", e)))?;
+        let rsa_signature = rsa_private
+            .sign_with_rng(&mut rng, Pss::new::<Sha256>(), &Sha256::digest(&message))
+            .map_err(|e| BenchmarkError::SignatureOperation(format!("RSA: {}", e)))?;
+
+        let ecdsa_signing = P256SigningKey::random(&mut rng);
+        let ecdsa_signature: P256Signature = ecdsa_signing.sign(&message);
+
+        let (dilithium_public, dilithium_secret) = mldsa44::keypair();
+        let dilithium_signature = mldsa44::detached_sign(&message, &dilithium_secret);
+        let (falcon_public, falcon_secret) = falcon512::keypair();
+        let falcon_signature = falcon512::detached_sign(&message, &falcon_secret);
+
+        let x25519_public = X25519PublicKey::from(&x25519_dalek::EphemeralSecret::random_from_rng(rng));
+        let p256_public = p256::ecdh::EphemeralSecret::random(&mut rng).public_key();
+        let (kyber_public, kyber_secret) = mlkem512::keypair();
+        let (_, kyber_ciphertext) = mlkem512::encapsulate(&kyber_public);
+
+        Ok(KeySet {
+            message,
+            ed25519_verifying: ed25519_signing.verifying_key(),
+            ed25519_signing,
+            ed25519_signature,
+            rsa_public: RsaPublicKey::from(&rsa_private),
+            rsa_private,
+            rsa_signature,
+            ecdsa_verifying: *ecdsa_signing.verifying_key(),
+            ecdsa_signing,
+            ecdsa_signature,
+            dilithium_public,
+            dilithium_secret,
+            dilithium_signature,
+            falcon_public,
+            falcon_secret,
+            falcon_signature,
+            x25519_public,
+            p256_public,
+            kyber_public,
+            kyber_secret,
+            kyber_ciphertext,
+        })
+    }
+}
+
+/// Kumpulan `KeySet` yang di-generate sekali di awal
+pub struct KeyPool {
+    pub sets: Vec<KeySet>,
+}
+
+impl KeyPool {
+    /// RSA keygen mendominasi waktunya (~100-200ms per set di release build)
+    pub fn generate(size: usize) -> Result<Self> {
+        let sets = (0..size.max(1)).map(|_| KeySet::generate()).collect::<Result<Vec<_>>>()?;
+        Ok(KeyPool { sets })
+    }
+
+    pub fn len(&self) -> usize {
+        self.sets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+}
+
+/// Waktu key generation per algoritma (operasi "Keygen"). RSA memakai sample
+/// lebih sedikit karena satu keygen bisa memakan ratusan milidetik.
+pub fn benchmark_keygen(config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let keygen = |name: &str, result: BenchmarkResult| {
+        result.to_metric(name.to_string(), "Keygen".to_string(), HashMap::new())
+    };
+    let mut metrics = Vec::new();
+
+    if config.includes("Ed25519") {
+        metrics.push(keygen("Ed25519", benchmark_operation_with(
+            || {
+                let mut secret_bytes = [0u8; 32];
+                OsRng.fill_bytes(&mut secret_bytes);
+                Ed25519SigningKey::from_bytes(&secret_bytes)
+            },
+            KEYGEN_ITERATIONS,
+            config,
+        )));
+    }
+    if config.includes("RSA-2048") {
+        metrics.push(keygen("RSA-2048", benchmark_operation_with(
+            || RsaPrivateKey::new(&mut OsRng, RSA_KEY_SIZE).expect("RSA key generation failed"),
+            RSA_KEYGEN_ITERATIONS,
+            config,
+        )));
+    }
+    if config.includes("ECDSA P-256") {
+        metrics.push(keygen("ECDSA P-256", benchmark_operation_with(
+            || P256SigningKey::random(&mut OsRng),
+            KEYGEN_ITERATIONS,
+            config,
+        )));
+    }
+    if config.includes("Dilithium (ML-DSA-44)") {
+        metrics.push(keygen("Dilithium (ML-DSA-44)", benchmark_operation_with(mldsa44::keypair, KEYGEN_ITERATIONS, config)));
+    }
+    if config.includes("Falcon-512") {
+        metrics.push(keygen("Falcon-512", benchmark_operation_with(falcon512::keypair, KEYGEN_ITERATIONS, config)));
+    }
+    if config.includes("X25519 Diffie-Hellman") {
+        metrics.push(keygen("X25519 Diffie-Hellman", benchmark_operation_with(
+            || X25519PublicKey::from(&x25519_dalek::EphemeralSecret::random_from_rng(OsRng)),
+            KEYGEN_ITERATIONS,
+            config,
+        )));
+    }
+    if config.includes("ECDH (P-256)") {
+        metrics.push(keygen("ECDH (P-256)", benchmark_operation_with(
+            || p256::ecdh::EphemeralSecret::random(&mut OsRng).public_key(),
+            KEYGEN_ITERATIONS,
+            config,
+        )));
+    }
+    if config.includes("Kyber (ML-KEM-512)") {
+        metrics.push(keygen("Kyber (ML-KEM-512)", benchmark_operation_with(mlkem512::keypair, KEYGEN_ITERATIONS, config)));
     }
+    metrics
 }
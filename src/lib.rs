@@ -8,29 +8,57 @@ pub mod constants;
 pub mod keys;
 pub mod measurement;
 pub mod models;
+pub mod cycles;
+pub mod alloc;
+pub mod stack;
+pub mod resources;
+pub mod throughput;
+pub mod preflight;
+pub mod cache;
+pub mod serialization;
+pub mod implementations;
+pub mod catalog;
+pub mod compare;
+pub mod baseline;
+pub mod environment;
+pub mod schema;
+pub mod export;
+pub mod html;
+pub mod terminal;
+pub mod normalize;
+#[cfg(target_os = "linux")]
+pub mod perf;
 
 // Legacy support - deprecated but kept for compatibility
 use std::time::Instant;
-use sysinfo::System;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
 
-#[deprecated(since = "0.2.0", note = "Use measurement::benchmark_operation instead")]
+/// Returns (duration μs, CPU usage % of this process, RSS growth in bytes).
+/// Reads only this process, not system-wide CPU/memory.
+#[deprecated(since = "0.2.0", note = "Use measurement::benchmark_operation and resources::ResourceSampler instead")]
 pub fn measure_resources<F>(sys: &mut System, f: F) -> (u64, u64, u64)
 where
     F: FnOnce(),
 {
-    sys.refresh_all();
-    let cpu_before = sys.global_cpu_usage() as u64;
-    let mem_before = sys.used_memory();
+    let pid = sysinfo::get_current_pid().ok();
+    let sample = |sys: &mut System| {
+        let pid = pid?;
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            false,
+            ProcessRefreshKind::nothing().with_cpu().with_memory(),
+        );
+        sys.process(pid).map(|p| (p.cpu_usage() as u64, p.memory()))
+    };
+
+    let (_, mem_before) = sample(sys).unwrap_or((0, 0));
 
     let start = Instant::now();
     f();
     let duration = start.elapsed().as_micros() as u64;
 
-    sys.refresh_all();
-    let cpu_after = sys.global_cpu_usage() as u64;
-    let mem_after = sys.used_memory();
-
-    let cpu_usage = cpu_after.saturating_sub(cpu_before);
+    // cpu_usage() adalah pemakaian sejak refresh sebelumnya, yaitu selama f()
+    let (cpu_usage, mem_after) = sample(sys).unwrap_or((0, 0));
     let mem_usage = mem_after.saturating_sub(mem_before);
 
     (duration, cpu_usage, mem_usage)
@@ -39,5 +67,5 @@ where
 // Re-exports untuk convenience
 pub use error::{BenchmarkError, Result};
 pub use constants::*;
-pub use keys::BenchmarkKeys;
-pub use measurement::{BenchmarkResult, benchmark_operation};
+pub use keys::{BenchmarkKeys, KeyPool};
+pub use measurement::{BenchmarkResult, MeasurementConfig, benchmark_operation, benchmark_operation_with};
@@ -1,96 +1,132 @@
-use crypto_benchmark::{signatures, kem, BenchmarkKeys};
-use crypto_benchmark::models::{BenchmarkReport, BenchmarkMetric};
+use crypto_benchmark::baseline::{BaselineCheck, BaselineStore, Thresholds};
+use crypto_benchmark::compare::{self, CompareOptions, ComparisonReport, Verdict};
+use crypto_benchmark::{catalog, environment, export, html, normalize, schema, terminal, signatures, kem, keys, BenchmarkKeys, MeasurementConfig};
+use crypto_benchmark::models::{ArtifactSizes, BatchVerifyResult, BenchmarkReport, BenchmarkMetric, CacheComparison, EnvironmentMetadata, InteropCheck, KeyPoolComparison, PreflightReport, ResourceUsage, ThroughputResult};
+use crypto_benchmark::preflight::{self, Strictness};
+use crypto_benchmark::terminal::{Align, Color, Style, Table};
+use crypto_benchmark::{cache, implementations, serialization, throughput, KeyPool};
+use crypto_benchmark::{COLD_CACHE_ITERATIONS, COLD_CACHE_KEY_SETS, DEFAULT_MEASUREMENT_ITERATIONS, STACK_PAINT_BYTES};
+use std::path::Path;
+use std::process::ExitCode;
 use std::time::Instant;
-use std::env;
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use cli::{exit, BaselineArgs, BaselineCommand, Cli, Command, CompareArgs, HtmlArgs, KeygenArgs, ListArgs, OutputArgs, OutputFormat, RunArgs, SchemaArgs, ThroughputArgs};
 
+mod cli;
 mod server;
 
-fn main() {
-    print_header();
-    
-    // Parse command line arguments
-    let args: Vec<String> = env::args().collect();
-    
-    let json_output = args.iter().any(|arg| arg == "--json");
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: crypto_benchmark::alloc::CountingAllocator = crypto_benchmark::alloc::CountingAllocator;
 
-    if args.len() > 1 && !json_output {
-        match args[1].as_str() {
-            "-h" | "--help" => {
-                print_usage();
-            }
-            "-v" | "--version" => {
-                println!("Crypto Benchmark v0.2.0");
-            }
-            "serve" => {
-                run_server();
-            }
-            "legacy" => {
-                println!("\n⚠️  Legacy mode is deprecated and unavailable.");
-                println!("Using optimized mode instead.\n");
-                run_optimized_benchmarks(false);
-            }
-            "comparison" => {
-                run_comparison();
-            }
-            _ => {
-                println!("\n❌ Unknown option: {}", args[1]);
-                print_usage();
-            }
+/// `Err` berisi exit code; pesan error sudah dicetak ke stderr
+type Outcome = Result<(), u8>;
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    if let Some(Command::Baseline(BaselineArgs { command: BaselineCommand::Check(check), .. })) = &cli.command {
+        // Hasil check berupa perbandingan, bukan report; CSV/Markdown tidak punya bentuknya
+        if matches!(check.run.output.format(), OutputFormat::Csv | OutputFormat::Md) {
+            Cli::command()
+                .error(ErrorKind::ArgumentConflict, "`baseline check` supports --format table or json only")
+                .exit();
         }
-    } else {
-        // Default: run optimized benchmarks
-        run_optimized_benchmarks(json_output);
+    }
+
+    let json_output = match &cli.command {
+        None => cli.run.output.machine_readable(),
+        Some(Command::Run(args)) => args.output.machine_readable(),
+        Some(Command::List(args)) => args.json,
+        Some(Command::Compare(args)) => args.json,
+        Some(Command::Baseline(args)) => match &args.command {
+            BaselineCommand::Save(save) => save.run.output.machine_readable(),
+            BaselineCommand::Check(check) => check.run.output.format() == OutputFormat::Json,
+            BaselineCommand::List => false,
+        },
+        Some(Command::Keygen(args)) => args.output.machine_readable(),
+        Some(Command::Throughput(args)) => args.output.machine_readable(),
+        Some(Command::Html(args)) => args.run.output.machine_readable(),
+        Some(Command::Schema(_)) => true,
+        Some(_) => false,
+    };
+    if !json_output {
+        print_header();
+    }
+
+    let outcome = match &cli.command {
+        None => run_optimized_benchmarks(&cli.run),
+        Some(Command::Run(args)) => run_optimized_benchmarks(args),
+        Some(Command::Serve(args)) => run_server(args.port, !args.no_hostname),
+        Some(Command::List(args)) => run_list(args),
+        Some(Command::Keygen(args)) => run_keygen_benchmarks(args),
+        Some(Command::Throughput(args)) => run_throughput_benchmarks(args),
+        Some(Command::Compare(args)) => run_comparison(args),
+        Some(Command::Baseline(args)) => run_baseline(args),
+        Some(Command::Html(args)) => run_html(args),
+        Some(Command::Schema(args)) => run_schema(args),
+        Some(Command::Legacy) => run_legacy_benchmarks(),
+    };
+    match outcome {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(code) => ExitCode::from(code),
     }
 }
 
 fn print_header() {
-    // Only print header if not in JSON mode
-    if !std::env::args().any(|arg| arg == "--json") {
-        println!("╔═══════════════════════════════════════════════════════════════╗");
-        println!("║         Cryptographic Benchmarking Tool v0.2.0               ║");
-        println!("║    Classical & Post-Quantum Cryptography Performance         ║");
-        println!("╚═══════════════════════════════════════════════════════════════╝");
-    }
-}
-
-fn print_usage() {
-    println!("\n📖 USAGE:");
-    println!("  cargo run --release [OPTIONS]\n");
-    println!("OPTIONS:");
-    println!("  (none)           Run optimized benchmarks (default)");
-    println!("  serve            Start the web interface");
-    println!("  --json           Output results in JSON format");
-    println!("  legacy           Run legacy mode (deprecated, uses optimized)");
-    println!("  comparison       Compare legacy vs optimized performance");
-    println!("  -h, --help       Show this help message");
-    println!("  -v, --version    Show version information\n");
-    println!("EXAMPLES:");
-    println!("  cargo run --release              # Run optimized benchmarks");
-    println!("  cargo run --release serve        # Start web interface");
-    println!("  cargo run --release -- --json    # Run and output JSON");
-    println!("  cargo run --release comparison   # Run comparison mode");
-    println!("  cargo bench                      # Run Criterion benchmarks\n");
-    println!("For more information, see README.md or CONTRIBUTING.md");
-}
-
-fn run_server() {
-    println!("🚀 Starting Crypto Benchmark Server...");
-    let rt = match tokio::runtime::Runtime::new() {
-        Ok(rt) => rt,
-        Err(e) => {
-            eprintln!("❌ Failed to start Tokio runtime: {}", e);
-            return;
+    println!("╔═══════════════════════════════════════════════════════════════╗");
+    println!("║         Cryptographic Benchmarking Tool v0.2.0               ║");
+    println!("║    Classical & Post-Quantum Cryptography Performance         ║");
+    println!("╚═══════════════════════════════════════════════════════════════╝");
+}
+
+/// Report ke stdout dalam `--format` (table sudah dicetak pemanggil) dan/atau
+/// JSON ke file (`--output`)
+fn emit_report(report: &BenchmarkReport, output: &OutputArgs) -> Outcome {
+    let json = serde_json::to_string_pretty(report).map_err(|e| {
+        eprintln!("❌ Failed to serialize report: {}", e);
+        exit::FAILURE
+    })?;
+    match output.format() {
+        OutputFormat::Table => {}
+        OutputFormat::Json => println!("{}", json),
+        OutputFormat::Csv => print!("{}", export::to_csv(report, &output.export_options())),
+        OutputFormat::Md => print!("{}", export::to_markdown(report, &output.export_options())),
+    }
+    if let Some(path) = &output.output {
+        std::fs::write(path, json + "\n").map_err(|e| {
+            eprintln!("❌ Failed to write {}: {}", path.display(), e);
+            exit::FAILURE
+        })?;
+        if !output.machine_readable() {
+            println!("\n📝 JSON report written to {}", path.display());
         }
-    };
+    }
+    Ok(())
+}
+
+fn generate_keys() -> Result<BenchmarkKeys, u8> {
+    BenchmarkKeys::generate().map_err(|e| {
+        eprintln!("❌ Failed to generate keys: {}", e);
+        eprintln!("   This may be due to insufficient system resources.");
+        exit::FAILURE
+    })
+}
 
-    if let Err(e) = rt.block_on(async { server::start_server(3000).await }) {
+fn run_server(port: u16, include_hostname: bool) -> Outcome {
+    println!("🚀 Starting Crypto Benchmark Server...");
+    let rt = tokio::runtime::Runtime::new().map_err(|e| {
+        eprintln!("❌ Failed to start Tokio runtime: {}", e);
+        exit::FAILURE
+    })?;
+
+    rt.block_on(async { server::start_server(port, include_hostname).await }).map_err(|e| {
         eprintln!("❌ Server error: {}", e);
-        std::process::exit(1);
-    }
+        exit::FAILURE
+    })
 }
 
-#[allow(dead_code)]
-fn run_legacy_benchmarks() {
+fn run_legacy_benchmarks() -> Outcome {
     println!("\n╔════════════════════════════════════════════════════════╗");
     println!("║              LEGACY BENCHMARK MODE                     ║");
     println!("║              (Not available - use optimized)           ║");
@@ -100,61 +136,251 @@ fn run_legacy_benchmarks() {
     println!("The optimized version is 70-80% faster with better accuracy.");
     println!("\nRunning optimized benchmarks instead...\n");
     
-    run_optimized_benchmarks(false);
+    run_optimized_benchmarks(&RunArgs::default())
+}
+
+fn run_list(args: &ListArgs) -> Outcome {
+    let entries = catalog::catalog();
+    if args.json {
+        let json = serde_json::to_string_pretty(&entries).map_err(|e| {
+            eprintln!("❌ Failed to serialize catalog: {}", e);
+            exit::FAILURE
+        })?;
+        println!("{}", json);
+        return Ok(());
+    }
+
+    let bytes = |size: Option<usize>| size.map_or("-".to_string(), |n| format!("{} B", n));
+    println!();
+    for entry in &entries {
+        let level = entry.nist_level.map_or("none (not quantum-safe)".to_string(), |level| format!("NIST level {}", level));
+        println!("{} ({} {})", entry.name, entry.family.label(), entry.kind.label());
+        println!("  Security:        {}, ~{}-bit classical", level, entry.classical_security_bits);
+        println!("  Operations:      {}", entry.operations.join(", "));
+        println!(
+            "  Sizes:           public key {} B, secret key {}, signature {}, ciphertext {}, shared secret {}",
+            entry.public_key_bytes,
+            bytes(entry.secret_key_bytes),
+            bytes(entry.signature_bytes),
+            bytes(entry.ciphertext_bytes),
+            bytes(entry.shared_secret_bytes)
+        );
+        println!("  Implementation:  {}", entry.implementations.join(", "));
+        if let Some(notes) = &entry.notes {
+            println!("  Notes:           {}", notes);
+        }
+        println!();
+    }
+    Ok(())
 }
 
-fn run_optimized_benchmarks(json_output: bool) {
+fn run_keygen_benchmarks(args: &KeygenArgs) -> Outcome {
+    let config = args.select.measurement_config();
+    if !args.output.machine_readable() {
+        println!("\n⏳ Timing key generation...\n");
+    }
+
+    let environment = environment::capture(&config, !args.output.no_hostname);
+    let start = Instant::now();
+    let metrics = keys::benchmark_keygen(&config);
+    let total_time = start.elapsed().as_secs_f64();
+
+    let mut report = BenchmarkReport {
+        keygen_time_secs: total_time,
+        total_time_secs: total_time,
+        keygen: metrics,
+        environment: Some(environment),
+        ..Default::default()
+    };
+    normalize::normalize(&mut report, &args.select.references());
+
+    if !args.output.machine_readable() {
+        print_metric_table(&report.keygen, Style::detect());
+        println!("\n  ⏱️  Total Time:      {:.2}s", total_time);
+    }
+    emit_report(&report, &args.output)
+}
+
+fn run_optimized_benchmarks(args: &RunArgs) -> Outcome {
+    let json_output = args.output.machine_readable();
     if !json_output {
         println!("\n╔════════════════════════════════════════════════════════╗");
         println!("║            OPTIMIZED BENCHMARK MODE                    ║");
         println!("║  Using pre-generated keys & statistical analysis       ║");
         println!("╚════════════════════════════════════════════════════════╝\n");
+    }
+
+    let report = collect_report(args, json_output)?;
+    if !json_output {
+        print_report(&report, &args.measurement_config());
+    }
+    emit_report(&report, &args.output)
+}
+
+/// Jalankan suite sesuai `args`. `quiet` menahan progress di stdout
+/// (warning tetap ke stderr).
+fn collect_report(args: &RunArgs, quiet: bool) -> Result<BenchmarkReport, u8> {
+    let config = args.measurement_config();
+    let config = &config;
+    let environment = environment::capture(config, !args.output.no_hostname);
+
+    // Pre-flight harus jalan di thread yang nanti melakukan pengukuran
+    // Pin dilepas di akhir fungsi ini, sebelum throughput atau run berikutnya
+    // (`compare --config-a/--config-b`)
+    let (preflight_report, _pin) = match args.preflight() {
+        Some(options) => {
+            let (report, pin) = preflight::run(options.pin_core);
+            print_preflight(&report, quiet);
+            if options.strictness == Strictness::Strict && !report.warnings.is_empty() {
+                eprintln!("❌ Pre-flight failed in strict mode; refusing to benchmark a noisy environment.");
+                return Err(exit::PREFLIGHT_REFUSED);
+            }
+            (Some(report), Some(pin))
+        }
+        None => (None, None),
+    };
+
+    if !quiet {
         println!("⏳ Generating benchmark keys...");
     }
     
     let start_keygen = Instant::now();
-    let keys = match BenchmarkKeys::generate() {
-        Ok(k) => k,
-        Err(e) => {
-            eprintln!("❌ Failed to generate keys: {}", e);
-            eprintln!("   This may be due to insufficient system resources.");
-            return;
-        }
-    };
+    let keys = generate_keys()?;
     let keygen_time = start_keygen.elapsed();
     
-    if !json_output {
+    if !quiet {
         println!("✓ All keys generated successfully ({:.2}s)\n", keygen_time.as_secs_f64());
         println!("🚀 Running benchmarks with statistical analysis...\n");
     }
 
-    let start_bench = Instant::now();
-    
     // Run signatures benchmark
-    let sig_metrics = signatures::benchmark_signatures_optimized(&keys);
+    let (sig_metrics, sig_resources) = signatures::benchmark_signatures_with_resources(&keys, config);
 
     // Run KEM benchmark
-    let kem_metrics = kem::benchmark_kem_optimized(&keys);
+    let (kem_metrics, kem_resources) = kem::benchmark_kem_with_resources(&keys, config);
+    let resources: Vec<ResourceUsage> = sig_resources.into_iter().chain(kem_resources).collect();
 
-    let bench_time = start_bench.elapsed();
-    let total_time = start_keygen.elapsed();
+    // Message-size sweep (opsional)
+    let message_sweep = args
+        .sweep_sizes()
+        .map(|sizes| signatures::benchmark_message_sweep(&keys, &sizes, config))
+        .unwrap_or_default();
 
-    if json_output {
-        let report = BenchmarkReport {
-            signatures: sig_metrics,
-            kem: kem_metrics,
-            keygen_time_secs: keygen_time.as_secs_f64(),
-            total_time_secs: total_time.as_secs_f64(),
-        };
-        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    // Cold vs warm cache (opsional), dengan pool key set terpisah
+    let cache_modes = if args.cold {
+        if !quiet {
+            println!("⏳ Generating {} key sets for cold-cache mode...", COLD_CACHE_KEY_SETS);
+        }
+        match KeyPool::generate(COLD_CACHE_KEY_SETS) {
+            Ok(pool) => cache::benchmark_cache_modes(&pool, COLD_CACHE_ITERATIONS, config),
+            Err(e) => {
+                eprintln!("⚠️  Skipping cold-cache mode: {}", e);
+                Vec::new()
+            }
+        }
     } else {
-        print_human_readable_report(&sig_metrics, &kem_metrics, keygen_time.as_secs_f64(), bench_time.as_secs_f64(), total_time.as_secs_f64());
+        Vec::new()
+    };
+
+    // Verifikasi di seluruh key pool (opsional)
+    let key_pool = match args.key_pool_size() {
+        Some(size) => {
+            if !quiet {
+                println!("⏳ Generating a pool of {} keypairs and signatures...", size);
+            }
+            match KeyPool::generate(size) {
+                Ok(pool) => signatures::benchmark_verify_key_pool(&pool, config),
+                Err(e) => {
+                    eprintln!("⚠️  Skipping key pool mode: {}", e);
+                    Vec::new()
+                }
+            }
+        }
+        None => Vec::new(),
+    };
+
+    // Ed25519 batch verification (opsional)
+    let batch_verify = args
+        .batch_sizes()
+        .filter(|_| config.includes("Ed25519"))
+        .map(|sizes| signatures::benchmark_ed25519_batch_verify(&sizes, config))
+        .unwrap_or_default();
+
+    // Encode/decode key, signature dan ciphertext (opsional)
+    let codec_metrics = if args.serialization {
+        serialization::benchmark_serialization(&keys, config)
+    } else {
+        Vec::new()
+    };
+
+    // Beberapa implementasi ML-KEM/ML-DSA side by side (opsional)
+    let (implementation_metrics, interop) = if args.implementations {
+        (implementations::benchmark_implementations(config), implementations::cross_check(config))
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    let total_time = start_keygen.elapsed();
+
+    let mut report = BenchmarkReport {
+        signatures: sig_metrics,
+        kem: kem_metrics,
+        keygen_time_secs: keygen_time.as_secs_f64(),
+        total_time_secs: total_time.as_secs_f64(),
+        resources,
+        message_sweep,
+        preflight: preflight_report,
+        cache_modes,
+        key_pool,
+        batch_verify,
+        serialization: codec_metrics,
+        implementations: implementation_metrics,
+        interop,
+        environment: Some(environment),
+        ..Default::default()
+    };
+    normalize::normalize(&mut report, &args.select.references());
+    Ok(report)
+}
+
+fn print_report(report: &BenchmarkReport, config: &MeasurementConfig) {
+    print_human_readable_report(
+        &report.signatures,
+        &report.kem,
+        &report.resources,
+        config,
+        report.keygen_time_secs,
+        report.total_time_secs - report.keygen_time_secs,
+        report.total_time_secs,
+    );
+    if let Some(environment) = &report.environment {
+        print_environment(environment);
+    }
+    if !report.message_sweep.is_empty() {
+        print_message_sweep(&report.message_sweep);
+    }
+    if !report.cache_modes.is_empty() {
+        print_cache_modes(&report.cache_modes);
+    }
+    if !report.key_pool.is_empty() {
+        print_key_pool(&report.key_pool);
+    }
+    if !report.batch_verify.is_empty() {
+        print_batch_verify(&report.batch_verify);
+    }
+    if !report.serialization.is_empty() {
+        print_serialization(&report.serialization);
+    }
+    if !report.implementations.is_empty() {
+        print_implementations(&report.implementations, &report.interop);
     }
 }
 
 fn print_human_readable_report(
     sig_metrics: &[BenchmarkMetric],
     kem_metrics: &[BenchmarkMetric],
+    resources: &[ResourceUsage],
+    config: &MeasurementConfig,
     keygen_time: f64,
     bench_time: f64,
     total_time: f64
@@ -163,45 +389,31 @@ fn print_human_readable_report(
     println!("                   SIGNATURE ALGORITHMS");
     println!("═══════════════════════════════════════════════════════════");
     println!("\n=== OPTIMIZED SIGNATURES BENCHMARK ===\n");
-
-    // Group by algorithm name to match original output style if possible,
-    // or just iterate. The original code printed headers for each algo.
-    // We can infer groups or just print sequentially.
-    // The metrics are already in order.
-    
-    let mut current_algo = "";
-    for metric in sig_metrics {
-        if metric.name != current_algo {
-            current_algo = &metric.name;
-            println!("{}:", current_algo);
-            for (k, v) in &metric.extra_info {
-                println!("  {}: {}", format_key(k), v);
-            }
-        }
-        print_metric(metric);
-    }
+    let style = Style::detect();
+    print_metric_table(sig_metrics, style);
 
     println!("\n═══════════════════════════════════════════════════════════");
     println!("              KEY EXCHANGE MECHANISMS (KEM)");
     println!("═══════════════════════════════════════════════════════════");
     println!("\n=== OPTIMIZED KEM BENCHMARK ===\n");
-
-    current_algo = "";
-    for metric in kem_metrics {
-        if metric.name != current_algo {
-            current_algo = &metric.name;
-            println!("{}:", current_algo);
-             for (k, v) in &metric.extra_info {
-                println!("  {}: {}", format_key(k), v);
-            }
+    print_metric_table(kem_metrics, style);
+    
+    if !resources.is_empty() {
+        println!("\n═══════════════════════════════════════════════════════════");
+        println!("              PROCESS RESOURCES (per algorithm)");
+        println!("═══════════════════════════════════════════════════════════\n");
+        for usage in resources {
+            print_resource_usage(usage);
         }
-        print_metric(metric);
     }
-    
+
     println!("\n╔════════════════════════════════════════════════════════╗");
     println!("║                 BENCHMARK SUMMARY                      ║");
     println!("╚════════════════════════════════════════════════════════╝");
-    println!("  📊 Statistics:      100 iterations per operation");
+    println!(
+        "  📊 Statistics:      {} iterations per operation",
+        config.iterations.unwrap_or(DEFAULT_MEASUREMENT_ITERATIONS)
+    );
     println!("  ⚡ Key Generation:  {:.2}s (one-time cost)", keygen_time);
     println!("  🔬 Benchmark Time:  {:.2}s (all operations)", bench_time);
     println!("  ⏱️  Total Time:      {:.2}s", total_time);
@@ -224,41 +436,665 @@ fn format_key(k: &str) -> String {
      .join(" ")
 }
 
-fn print_metric(metric: &BenchmarkMetric) {
-    println!("  {} Performance:", metric.operation);
-    println!("    Mean:   {:>10.2} μs", metric.mean_micros);
-    println!("    Min:    {:>10.2} μs", metric.min_micros);
-    println!("    Max:    {:>10.2} μs", metric.max_micros);
-    println!("    StdDev: {:>10.2} μs", metric.std_dev_micros);
-    println!("    Iterations: {}", metric.iterations);
+/// Satu baris per metric, kolom opsional hanya jika ada metric yang mengisinya,
+/// dan bar chart mean latency relatif terhadap yang paling lambat di tabel.
+/// Mean tercepat/terlambat per operasi diberi warna hijau/merah; faktor
+/// relatif terhadap algoritma acuan muncul jika report sudah dinormalisasi.
+fn print_metric_table(metrics: &[BenchmarkMetric], style: Style) {
+    if metrics.is_empty() {
+        println!("  (no metrics)");
+        return;
+    }
+    let any = |f: fn(&BenchmarkMetric) -> bool| metrics.iter().any(f);
+    let show_calls = any(|m| m.calls_per_sample > 1);
+    let show_cycles = any(|m| m.cycles.is_some());
+    // RDTSCP/CNTVCT menghitung ticks berfrekuensi konstan, bukan core cycles
+    let mut cycle_units: Vec<&str> = metrics.iter().filter_map(|m| m.cycles.as_ref()).map(|c| c.source.unit()).collect();
+    cycle_units.sort_unstable();
+    cycle_units.dedup();
+    let cycles_header = match cycle_units.as_slice() {
+        ["TSC ticks"] => "TSC ticks/op",
+        ["timer ticks"] => "Ticks/op",
+        ["cycles"] => "Cycles/op",
+        _ => "Cycles|ticks/op",
+    };
+    let show_instructions = any(|m| m.hardware_counters.as_ref().is_some_and(|hw| hw.instructions_per_op.is_some()));
+    let show_allocations = any(|m| m.allocations.is_some());
+    let show_stack = any(|m| m.peak_stack_bytes.is_some());
+    // Satu acuan untuk seluruh tabel → namanya masuk header
+    let mut references: Vec<&str> = metrics.iter().filter_map(|m| m.relative.as_ref()).map(|r| r.reference.as_str()).collect();
+    references.sort_unstable();
+    references.dedup();
+    let relative_header = match references.as_slice() {
+        [] => None,
+        [reference] => Some(format!("× {}", reference)),
+        _ => Some("× Reference".to_string()),
+    };
+
+    let mut columns = vec![
+        ("Algorithm", Align::Left),
+        ("Operation", Align::Left),
+        ("Mean (μs)", Align::Right),
+        ("± StdDev", Align::Right),
+        ("Min", Align::Right),
+        ("Max", Align::Right),
+        ("Samples", Align::Right),
+    ];
+    let optional = [
+        (show_calls, "Calls/sample"),
+        (show_cycles, cycles_header),
+        (show_instructions, "Instr/op"),
+        (show_allocations, "Allocs/op"),
+        (show_allocations, "Heap B/op"),
+        (show_stack, "Stack B"),
+    ];
+    columns.extend(optional.iter().filter(|(show, _)| *show).map(|(_, header)| (*header, Align::Right)));
+    if let Some(header) = &relative_header {
+        columns.push((header.as_str(), Align::Right));
+    }
+    columns.push(("Mean latency", Align::Left));
+    let mut table = Table::new(&columns);
+
+    let slowest = metrics.iter().map(|m| m.mean_micros).fold(0.0, f64::max);
+    let extreme = |operation: &str, pick: fn(f64, f64) -> f64| {
+        metrics.iter().filter(|m| m.operation == operation).map(|m| m.mean_micros).reduce(pick)
+    };
+    let per_operation = |operation: &str| metrics.iter().filter(|m| m.operation == operation).count();
+    let fmt_opt = |v: Option<f64>| v.map_or("-".to_string(), |v| format!("{:.0}", v));
+
+    let mut previous = "";
+    for m in metrics {
+        let name = if m.name != previous { m.name.as_str() } else { "" };
+        previous = &m.name;
+
+        let mean = format!("{:.2}", m.mean_micros);
+        let mean = if per_operation(&m.operation) < 2 {
+            mean
+        } else if Some(m.mean_micros) == extreme(&m.operation, f64::min) {
+            style.paint(&mean, Color::Green)
+        } else if Some(m.mean_micros) == extreme(&m.operation, f64::max) {
+            style.paint(&mean, Color::Red)
+        } else {
+            mean
+        };
+
+        let mut row = vec![
+            name.to_string(),
+            m.operation.clone(),
+            mean,
+            format!("{:.2}", m.std_dev_micros),
+            format!("{:.2}", m.min_micros),
+            format!("{:.2}", m.max_micros),
+            m.iterations.to_string(),
+        ];
+        if show_calls {
+            row.push(m.calls_per_sample.to_string());
+        }
+        if show_cycles {
+            row.push(fmt_opt(m.cycles.as_ref().map(|c| c.mean_per_op)));
+        }
+        if show_instructions {
+            row.push(fmt_opt(m.hardware_counters.as_ref().and_then(|hw| hw.instructions_per_op)));
+        }
+        if show_allocations {
+            row.push(m.allocations.as_ref().map_or("-".to_string(), |a| format!("{:.1}", a.allocations_per_op)));
+            row.push(fmt_opt(m.allocations.as_ref().map(|a| a.bytes_per_op)));
+        }
+        if show_stack {
+            row.push(match m.peak_stack_bytes {
+                Some(_) if m.peak_stack_saturated => format!("≥ {} MiB", STACK_PAINT_BYTES / (1024 * 1024)),
+                Some(bytes) => bytes.to_string(),
+                None => "-".to_string(),
+            });
+        }
+        if relative_header.is_some() {
+            row.push(m.relative.as_ref().map_or("-".to_string(), |r| format!("{:.2}×", r.factor)));
+        }
+        row.push(style.paint(&terminal::bar(m.mean_micros, slowest, terminal::BAR_WIDTH), Color::Cyan));
+        table.row(row);
+    }
+    print!("{}", table.render("  ", style));
+
+    print_sizes(metrics, style);
+    print_notes(metrics, style);
 }
 
-#[allow(dead_code)]
-fn run_comparison() {
-    println!("\n╔════════════════════════════════════════════════════════╗");
-    println!("║              COMPARISON BENCHMARK MODE                 ║");
-    println!("╚════════════════════════════════════════════════════════╝");
-    
-    println!("\n[1/2] Running LEGACY benchmarks...");
-    let legacy_start = Instant::now();
-    run_legacy_benchmarks();
-    let legacy_time = legacy_start.elapsed();
-    
-    println!("\n\n[2/2] Running OPTIMIZED benchmarks...");
-    let optimized_start = Instant::now();
-    run_optimized_benchmarks(false);
-    let optimized_time = optimized_start.elapsed();
-    
+/// Ukuran key/signature/ciphertext per algoritma (dari metric pertamanya)
+fn print_sizes(metrics: &[BenchmarkMetric], style: Style) {
+    let mut algorithms: Vec<&BenchmarkMetric> = Vec::new();
+    for m in metrics {
+        if !m.sizes.entries().is_empty() && !algorithms.iter().any(|a| a.name == m.name) {
+            algorithms.push(m);
+        }
+    }
+    if algorithms.is_empty() {
+        return;
+    }
+
+    // Kolom mengikuti urutan field ArtifactSizes, hanya yang terisi
+    let mut labels: Vec<&str> = Vec::new();
+    for (label, _) in algorithms.iter().flat_map(|m| m.sizes.entries()) {
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+    labels.sort_by_key(|label| ArtifactSizes::LABELS.iter().position(|l| l == label));
+
+    let mut columns = vec![("Algorithm", Align::Left)];
+    columns.extend(labels.iter().map(|label| (*label, Align::Right)));
+    let mut table = Table::new(&columns);
+    for m in algorithms {
+        let entries = m.sizes.entries();
+        let mut row = vec![m.name.clone()];
+        row.extend(labels.iter().map(|label| {
+            entries.iter().find(|(l, _)| l == label).map_or("-".to_string(), |(_, size)| size.to_string())
+        }));
+        table.row(row);
+    }
+    println!();
+    print!("{}", table.render("  ", style));
+}
+
+/// `extra_info` per metric, urut nama key supaya output deterministik
+fn print_notes(metrics: &[BenchmarkMetric], style: Style) {
+    for m in metrics {
+        let notes: std::collections::BTreeMap<&String, &String> = m.extra_info.iter().collect();
+        for (key, value) in notes {
+            let line = format!("  {} {}: {}: {}", m.name, m.operation, format_key(key), value);
+            println!("{}", style.paint(&line, Color::Dim));
+        }
+        if m.calls_per_sample > 1 {
+            let line = format!(
+                "  {} {}: timer overhead {:.0} ns subtracted per sample",
+                m.name, m.operation, m.timer_overhead_nanos
+            );
+            println!("{}", style.paint(&line, Color::Dim));
+        }
+    }
+}
+
+fn run_throughput_benchmarks(args: &ThroughputArgs) -> Outcome {
+    let json_output = args.output.machine_readable();
+    if !json_output {
+        println!("\n╔════════════════════════════════════════════════════════╗");
+        println!("║              THROUGHPUT BENCHMARK MODE                 ║");
+        println!("║  Shared keys, 1..N threads, fixed duration per run     ║");
+        println!("╚════════════════════════════════════════════════════════╝\n");
+    }
+
+    let config = args.select.measurement_config();
+    let environment = environment::capture(&config, !args.output.no_hostname);
+    let start_keygen = Instant::now();
+    let keys = generate_keys()?;
+    let keygen_time = start_keygen.elapsed();
+
+    let max_threads = args
+        .threads
+        .unwrap_or_else(|| std::thread::available_parallelism().map_or(1, |n| n.get()));
+    let duration = std::time::Duration::from_millis(args.duration_ms);
+    if !json_output {
+        println!("🚀 Running on up to {} threads, {} ms per run...\n", max_threads, args.duration_ms);
+    }
+
+    let results = throughput::benchmark_throughput(&keys, max_threads, duration, &config);
+
+    if !json_output {
+        print_throughput_results(&results);
+    }
+
+    let report = BenchmarkReport {
+        keygen_time_secs: keygen_time.as_secs_f64(),
+        total_time_secs: start_keygen.elapsed().as_secs_f64(),
+        throughput: results,
+        environment: Some(environment),
+        ..Default::default()
+    };
+    emit_report(&report, &args.output)
+}
+
+fn print_throughput_results(results: &[ThroughputResult]) {
+    let mut current = (String::new(), String::new());
+    for result in results {
+        if (&result.name, &result.operation) != (&current.0, &current.1) {
+            current = (result.name.clone(), result.operation.clone());
+            println!("{} {}:", result.name, result.operation);
+            println!("  {:>7}  {:>14}  {:>14}  {:>10}", "Threads", "Ops/sec", "Ops/sec/thread", "Scaling");
+        }
+        println!(
+            "  {:>7}  {:>14.0}  {:>14.0}  {:>9.0}%",
+            result.threads,
+            result.ops_per_sec,
+            result.ops_per_sec_per_thread,
+            result.scaling_efficiency * 100.0
+        );
+    }
+}
+
+/// Warning selalu ke stderr supaya output `--json` tetap valid
+fn print_environment(env: &EnvironmentMetadata) {
+    println!("\n🖥️  Environment ({}):", env.timestamp);
+    let cpu = env.cpu_model.as_deref().unwrap_or("unknown CPU");
+    let cores = env.physical_cores.map_or(String::new(), |n| format!("{} cores / ", n));
+    let freq = env.cpu_frequency_mhz.map_or(String::new(), |mhz| format!(" @ {} MHz", mhz));
+    println!("  CPU: {} ({}{} threads){}", cpu, cores, env.logical_cpus, freq);
+    let os = [env.os_version.as_deref().or(env.os.as_deref()), env.kernel_version.as_deref()];
+    println!("  OS: {} ({})", os.iter().flatten().copied().collect::<Vec<_>>().join(", kernel "), env.arch);
+    println!("  Build: {} for {}, {} profile, opt-level {}", env.rustc_version, env.target, env.profile, env.opt_level);
+    if let Some(host) = &env.hostname {
+        println!("  Host: {}", host);
+    }
+}
+
+fn print_preflight(report: &PreflightReport, json_output: bool) {
+    if !json_output {
+        println!("🔍 Pre-flight checks:");
+        let core = report.pinned_core.map_or("not pinned".to_string(), |c| format!("CPU {}", c));
+        println!("  Pinned to: {}", core);
+        println!("  Governor: {}", report.governor.as_deref().unwrap_or("unknown"));
+        let turbo = report.turbo_enabled.map_or("unknown", |on| if on { "enabled" } else { "disabled" });
+        println!("  Turbo: {}", turbo);
+        if let Some(load) = report.load_average_1m {
+            println!("  Load average (1m): {:.2} on {} logical CPUs", load, report.logical_cpus);
+        }
+        if report.warnings.is_empty() {
+            println!("  ✓ Environment looks quiet\n");
+        }
+    }
+    for warning in &report.warnings {
+        eprintln!("  ⚠️  {}", warning);
+    }
+    if !json_output && !report.warnings.is_empty() {
+        println!();
+    }
+}
+
+/// Satu baris per (algoritma, operasi), satu kolom per implementasi
+fn print_implementations(metrics: &[BenchmarkMetric], interop: &[InteropCheck]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("              IMPLEMENTATION COMPARISON");
+    println!("═══════════════════════════════════════════════════════════\n");
+
+    let available = implementations::available_implementations();
+    print!("  {:<22} {:<12}", "Algorithm", "Op");
+    for implementation in &available {
+        print!(" {:>14}", format!("{} (μs)", implementation));
+    }
+    println!();
+
+    let mut rows: Vec<(&str, &str)> = Vec::new();
+    for metric in metrics {
+        let row = (metric.name.as_str(), metric.operation.as_str());
+        if !rows.contains(&row) {
+            rows.push(row);
+        }
+    }
+    for (name, operation) in rows {
+        print!("  {:<22} {:<12}", name, operation);
+        for implementation in &available {
+            let mean = metrics
+                .iter()
+                .find(|m| m.name == name && m.operation == operation && m.implementation.as_deref() == Some(*implementation))
+                .map_or("-".to_string(), |m| format!("{:.2}", m.mean_micros));
+            print!(" {:>14}", mean);
+        }
+        println!();
+    }
+
+    if interop.is_empty() {
+        println!("\n  ℹ️  Build with --features rustcrypto to compare against RustCrypto ml-kem/ml-dsa");
+    }
+    for check in interop {
+        let status = if check.passed { "✓" } else { "✗" };
+        println!("\n  {} {}: {}", status, check.algorithm, check.direction);
+        if let Some(detail) = &check.detail {
+            println!("      {}", detail);
+        }
+    }
+}
+
+fn print_serialization(metrics: &[BenchmarkMetric]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("              ENCODING / DECODING");
+    println!("═══════════════════════════════════════════════════════════\n");
+    let mut current_algo = "";
+    for metric in metrics {
+        if metric.name != current_algo {
+            current_algo = &metric.name;
+            println!("{}:", current_algo);
+        }
+        let size = metric.sizes.encoded.map_or("-".to_string(), |size| size.to_string());
+        println!("  {:<40} {:>12.3} μs  ({})", metric.operation, metric.mean_micros, size);
+    }
+}
+
+fn print_batch_verify(results: &[BatchVerifyResult]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("              ED25519 BATCH VERIFICATION");
+    println!("═══════════════════════════════════════════════════════════\n");
+    println!("  {:>6} {:>14} {:>14} {:>14} {:>9}", "Batch", "Batch (μs)", "Per sig (μs)", "Single (μs)", "Speedup");
+    for result in results {
+        println!(
+            "  {:>6} {:>14.2} {:>14.2} {:>14.2} {:>8.2}x",
+            result.batch_size,
+            result.batch.mean_micros,
+            result.per_signature_micros,
+            result.single_verify_micros,
+            result.speedup
+        );
+    }
+}
+
+fn print_key_pool(comparisons: &[KeyPoolComparison]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("              VERIFY: SINGLE KEY vs KEY POOL");
+    println!("═══════════════════════════════════════════════════════════\n");
+    println!("  {:<22} {:>6} {:>14} {:>14} {:>10}", "Algorithm", "Keys", "1 key (μs)", "Pool (μs)", "Diff");
+    for comparison in comparisons {
+        println!(
+            "  {:<22} {:>6} {:>14.2} {:>14.2} {:>+9.1}%",
+            comparison.name,
+            comparison.pool_size,
+            comparison.single_key.mean_micros,
+            comparison.key_pool.mean_micros,
+            comparison.difference_percent
+        );
+    }
+}
+
+fn print_cache_modes(comparisons: &[CacheComparison]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("                 WARM vs COLD CACHE");
+    println!("═══════════════════════════════════════════════════════════\n");
+    println!("  {:<22} {:<13} {:>12} {:>12} {:>10}", "Algorithm", "Op", "Warm (μs)", "Cold (μs)", "Cold/Warm");
+    for comparison in comparisons {
+        println!(
+            "  {:<22} {:<13} {:>12.2} {:>12.2} {:>9.2}x",
+            comparison.name,
+            comparison.operation,
+            comparison.warm.mean_micros,
+            comparison.cold.mean_micros,
+            comparison.cold_to_warm_ratio
+        );
+    }
+}
+
+fn print_message_sweep(metrics: &[BenchmarkMetric]) {
+    println!("\n═══════════════════════════════════════════════════════════");
+    println!("              SIGNATURE MESSAGE-SIZE SWEEP");
+    println!("═══════════════════════════════════════════════════════════\n");
+    println!("  {:<22} {:<7} {:>10} {:>14} {:>12}", "Algorithm", "Op", "Message", "Mean (μs)", "MiB/s");
+    for metric in metrics {
+        let size = metric.message_size.unwrap_or_default();
+        let size_label = match size {
+            s if s >= 1024 * 1024 && s % (1024 * 1024) == 0 => format!("{} MiB", s / (1024 * 1024)),
+            s if s >= 1024 && s % 1024 == 0 => format!("{} KiB", s / 1024),
+            s => format!("{} B", s),
+        };
+        println!(
+            "  {:<22} {:<7} {:>10} {:>14.2} {:>12.2}",
+            metric.name,
+            metric.operation,
+            size_label,
+            metric.mean_micros,
+            metric.bytes_per_sec.unwrap_or_default() / 1048576.0
+        );
+    }
+}
+
+fn print_resource_usage(usage: &ResourceUsage) {
+    let secs = |v: Option<f64>| v.map_or("n/a".to_string(), |v| format!("{:.3}s", v));
+    println!("{}:", usage.algorithm);
+    println!(
+        "  CPU:    {:.3}s total (user {}, sys {}) over {:.3}s wall, {:.0}%",
+        usage.cpu_total_secs,
+        secs(usage.cpu_user_secs),
+        secs(usage.cpu_system_secs),
+        usage.wall_time_secs,
+        usage.cpu_utilization_percent
+    );
+    println!(
+        "  RSS:    {:.1} MiB → {:.1} MiB (peak {:.1} MiB)",
+        usage.rss_start_bytes as f64 / 1048576.0,
+        usage.rss_end_bytes as f64 / 1048576.0,
+        usage.rss_peak_bytes as f64 / 1048576.0
+    );
+}
+
+fn load_report(path: &Path) -> Result<BenchmarkReport, u8> {
+    let contents = std::fs::read_to_string(path).map_err(|e| {
+        eprintln!("❌ Failed to read {}: {}", path.display(), e);
+        exit::FAILURE
+    })?;
+    schema::load_report(&contents).map_err(|e| {
+        eprintln!("❌ {} is not a benchmark report: {}", path.display(), e);
+        exit::FAILURE
+    })
+}
+
+fn run_html(args: &HtmlArgs) -> Outcome {
+    let quiet = args.run.output.machine_readable();
+    let report = report_from(args.from.as_deref(), &args.run, quiet)?;
+    if args.from.is_none() && !quiet {
+        print_report(&report, &args.run.measurement_config());
+    }
+    std::fs::write(&args.path, html::render(&report)).map_err(|e| {
+        eprintln!("❌ Failed to write {}: {}", args.path.display(), e);
+        exit::FAILURE
+    })?;
+    if quiet {
+        emit_report(&report, &OutputArgs { output: None, ..args.run.output.clone() })
+    } else {
+        println!("\n📄 HTML report written to {}", args.path.display());
+        Ok(())
+    }
+}
+
+fn run_schema(args: &SchemaArgs) -> Outcome {
+    match &args.upgrade {
+        Some(path) => emit_report(&load_report(path)?, &OutputArgs { json: true, ..Default::default() }),
+        None => {
+            let schema = serde_json::to_string_pretty(&schema::json_schema()).map_err(|e| {
+                eprintln!("❌ Failed to serialize schema: {}", e);
+                exit::FAILURE
+            })?;
+            println!("{}", schema);
+            Ok(())
+        }
+    }
+}
+
+/// Satu konfigurasi A/B: progress ke stderr supaya `--json` tetap valid
+fn run_configuration(label: &str, args: &RunArgs) -> Result<BenchmarkReport, u8> {
+    eprintln!("⏳ Running configuration {}...", label);
+    let report = collect_report(args, true)?;
+    if args.output.output.is_some() {
+        emit_report(&report, &OutputArgs { json: false, format: OutputFormat::Table, ..args.output.clone() })?;
+    }
+    Ok(report)
+}
+
+fn run_comparison(args: &CompareArgs) -> Outcome {
+    let (baseline, candidate) = match (&args.config_a, &args.config_b) {
+        (Some(config_a), Some(config_b)) => (run_configuration("A", config_a)?, run_configuration("B", config_b)?),
+        _ => (load_report(&args.reports[0])?, load_report(&args.reports[1])?),
+    };
+
+    let options = CompareOptions { significance_level: args.alpha, min_change_percent: args.min_change };
+    let comparison = compare::compare_reports(&baseline, &candidate, &options);
+
+    if args.json {
+        let json = serde_json::to_string_pretty(&comparison).map_err(|e| {
+            eprintln!("❌ Failed to serialize comparison: {}", e);
+            exit::FAILURE
+        })?;
+        println!("{}", json);
+    } else {
+        print_comparison(&comparison);
+    }
+    Ok(())
+}
+
+fn print_comparison(comparison: &ComparisonReport) {
     println!("\n╔════════════════════════════════════════════════════════╗");
-    println!("║                 PERFORMANCE COMPARISON                 ║");
+    println!("║                 A/B COMPARISON                         ║");
     println!("╚════════════════════════════════════════════════════════╝");
-    println!("  Legacy Mode:     {:.2}s", legacy_time.as_secs_f64());
-    println!("  Optimized Mode:  {:.2}s", optimized_time.as_secs_f64());
-    
-    let speedup = legacy_time.as_secs_f64() / optimized_time.as_secs_f64();
-    let improvement = (legacy_time.as_secs_f64() - optimized_time.as_secs_f64()) 
-                      / legacy_time.as_secs_f64() * 100.0;
-    
-    println!("\n  Speedup:         {:.2}x faster", speedup);
-    println!("  Improvement:     {:.1}% reduction in time", improvement);
+    println!(
+        "  Welch's t-test, significant at p < {}, minimum change {}%",
+        comparison.significance_level, comparison.min_change_percent
+    );
+
+    let width = comparison.deltas.iter().map(|d| d.label().chars().count()).max().unwrap_or(0).max(6);
+    let mut section = "";
+    for delta in &comparison.deltas {
+        if delta.section != section {
+            section = &delta.section;
+            println!("\n{}:", section);
+            println!(
+                "  {:<width$} {:>14} {:>14} {:>9} {:>9}  Verdict",
+                "Metric", "Baseline (μs)", "Candidate (μs)", "Change", "p-value"
+            );
+        }
+        let marker = match delta.verdict {
+            Verdict::Improved => "✅",
+            Verdict::Regressed => "❌",
+            Verdict::Unchanged => "  ",
+        };
+        println!(
+            "  {:<width$} {:>14.2} {:>14.2} {:>+8.1}% {:>9.4}  {} {}",
+            delta.label(),
+            delta.baseline_mean_micros,
+            delta.candidate_mean_micros,
+            delta.percent_change,
+            delta.test.p_value,
+            marker,
+            delta.verdict.label()
+        );
+    }
+
+    for (title, labels) in [("Only in baseline", &comparison.only_in_baseline), ("Only in candidate", &comparison.only_in_candidate)] {
+        if !labels.is_empty() {
+            println!("\n{}:", title);
+            for label in labels {
+                println!("  {}", label);
+            }
+        }
+    }
+
+    println!(
+        "\n  {} improved, {} regressed, {} unchanged",
+        comparison.count(Verdict::Improved),
+        comparison.count(Verdict::Regressed),
+        comparison.count(Verdict::Unchanged)
+    );
+}
+
+/// Report dari file (`--from`) atau dari menjalankan suite; `-o` tetap menulis
+/// report hasil run
+fn report_from(from: Option<&Path>, run: &RunArgs, quiet: bool) -> Result<BenchmarkReport, u8> {
+    if let Some(path) = from {
+        return load_report(path);
+    }
+    let report = collect_report(run, quiet)?;
+    if run.output.output.is_some() {
+        emit_report(&report, &OutputArgs { json: false, format: OutputFormat::Table, ..run.output.clone() })?;
+    }
+    Ok(report)
+}
+
+fn run_baseline(args: &BaselineArgs) -> Outcome {
+    let store = BaselineStore::new(&args.dir);
+    let store_error = |e: std::io::Error| {
+        eprintln!("❌ Baseline store {}: {}", store.dir().display(), e);
+        exit::FAILURE
+    };
+
+    match &args.command {
+        BaselineCommand::List => {
+            let names = store.list().map_err(store_error)?;
+            if names.is_empty() {
+                println!("\nNo baselines in {}", store.dir().display());
+            }
+            for name in names {
+                println!("  {}", name);
+            }
+            Ok(())
+        }
+        BaselineCommand::Save(save) => {
+            let json_output = save.run.output.machine_readable();
+            let report = report_from(save.from.as_deref(), &save.run, json_output)?;
+            if json_output {
+                emit_report(&report, &OutputArgs { output: None, ..save.run.output.clone() })?;
+            } else if save.from.is_none() {
+                print_report(&report, &save.run.measurement_config());
+            }
+            let path = store.save(&save.name, &report).map_err(store_error)?;
+            eprintln!("💾 Saved baseline '{}' to {}", save.name, path.display());
+            Ok(())
+        }
+        BaselineCommand::Check(check) => {
+            let json_output = check.run.output.format() == OutputFormat::Json;
+            let baseline_report = store.load(&check.name).map_err(|e| {
+                eprintln!("❌ Cannot load baseline '{}' from {}: {}", check.name, store.dir().display(), e);
+                exit::FAILURE
+            })?;
+            let report = report_from(check.from.as_deref(), &check.run, true)?;
+
+            let options = CompareOptions { significance_level: check.alpha, ..Default::default() };
+            let comparison = compare::compare_reports(&baseline_report, &report, &options);
+            let thresholds = Thresholds { default_percent: check.threshold, per_metric: check.metric_threshold.clone() };
+            let result = BaselineCheck::new(check.name.clone(), comparison, thresholds);
+
+            if json_output {
+                let json = serde_json::to_string_pretty(&result).map_err(|e| {
+                    eprintln!("❌ Failed to serialize baseline check: {}", e);
+                    exit::FAILURE
+                })?;
+                println!("{}", json);
+            } else {
+                print_comparison(&result.comparison);
+                print_regressions(&result);
+            }
+
+            if !result.regressions.is_empty() {
+                Err(exit::REGRESSION)
+            } else if !result.missing.is_empty() {
+                Err(exit::MISSING_METRICS)
+            } else {
+                Ok(())
+            }
+        }
+    }
+}
+
+fn print_regressions(check: &BaselineCheck) {
+    if !check.missing.is_empty() {
+        println!(
+            "\n❌ {} metric(s) from baseline '{}' missing in this run:",
+            check.missing.len(),
+            check.baseline
+        );
+        for label in &check.missing {
+            println!("  {}", label);
+        }
+    }
+    if check.regressions.is_empty() {
+        if check.missing.is_empty() {
+            println!(
+                "\n✅ No regressions beyond threshold (default {}%) against baseline '{}'",
+                check.thresholds.default_percent, check.baseline
+            );
+        }
+        return;
+    }
+    println!(
+        "\n❌ {} regression(s) beyond threshold against baseline '{}':",
+        check.regressions.len(),
+        check.baseline
+    );
+    for regression in &check.regressions {
+        println!(
+            "  {} ({}): {:+.1}% > {}% (p = {:.4})",
+            regression.metric, regression.section, regression.percent_change, regression.threshold_percent, regression.p_value
+        );
+    }
 }
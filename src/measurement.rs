@@ -1,6 +1,47 @@
 // Improved measurement system
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::OnceLock;
 use std::time::{Duration, Instant};
-use crate::models::BenchmarkMetric;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use crate::constants::{
+    ALLOCATION_SAMPLE_CALLS, CACHE_EVICTION_BYTES, MAX_CALLS_PER_SAMPLE, MIN_SAMPLE_TIME_NS, TIMER_CALIBRATION_ROUNDS,
+    TIMER_RESOLUTION_MULTIPLE,
+};
+use crate::cycles::CycleCounter;
+use crate::models::{AllocationStats, BenchmarkMetric, CycleStats, HardwareCounterStats};
+
+/// Pengukuran opsional di samping wall-clock time
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct MeasurementConfig {
+    /// Ukur CPU cycles per operasi (RDTSCP / CNTVCT_EL0 / perf_event)
+    pub count_cycles: bool,
+    /// Linux perf counters (instructions, branch misses, cache misses) per operasi
+    pub hardware_counters: bool,
+    /// Alokasi heap per operasi; butuh `alloc::CountingAllocator` terpasang
+    pub track_allocations: bool,
+    /// Stack high-water mark per operasi (thread khusus dengan painted stack)
+    pub measure_stack: bool,
+    /// Override jumlah sample per operasi (default: ditentukan per algoritma)
+    pub iterations: Option<usize>,
+    /// Filter nama algoritma untuk suite utama; kosong = semua
+    pub algorithms: Vec<String>,
+}
+
+impl MeasurementConfig {
+    /// `true` jika `name` lolos filter `algorithms`. Pencocokan case-insensitive
+    /// dan hanya melihat huruf/angka, jadi "ml-kem", "p256" dan "ed25519"
+    /// cocok dengan "Kyber (ML-KEM-512)", "ECDH (P-256)" dan "Ed25519".
+    pub fn includes(&self, name: &str) -> bool {
+        let normalize = |s: &str| -> String {
+            s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+        };
+        let name = normalize(name);
+        self.algorithms.is_empty() || self.algorithms.iter().any(|filter| name.contains(&normalize(filter)))
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct BenchmarkResult {
@@ -9,91 +50,466 @@ pub struct BenchmarkResult {
     pub max_duration: Duration,
     pub std_deviation: f64,
     pub iterations: usize,
+    /// Jumlah pemanggilan per timed sample (1 = timing per call)
+    pub calls_per_sample: usize,
+    /// Overhead timer yang sudah dikurangkan dari setiap sample
+    pub timer_overhead: Duration,
+    pub cycles: Option<CycleStats>,
+    pub hardware_counters: Option<HardwareCounterStats>,
+    pub allocations: Option<AllocationStats>,
+    pub peak_stack_bytes: Option<usize>,
+    /// `peak_stack_bytes` mencapai batas area yang di-paint
+    pub peak_stack_saturated: bool,
+    /// Catatan untuk `extra_info`, mis. alasan pengukuran opsional tidak tersedia
+    pub notes: HashMap<String, String>,
 }
 
 impl BenchmarkResult {
     pub fn print(&self, label: &str) {
         println!("  {}", label);
-        println!("    Mean:   {:>10.2} μs", self.mean_duration.as_micros() as f64);
-        println!("    Min:    {:>10.2} μs", self.min_duration.as_micros() as f64);
-        println!("    Max:    {:>10.2} μs", self.max_duration.as_micros() as f64);
+        println!("    Mean:   {:>10.2} μs", self.mean_duration.as_secs_f64() * 1e6);
+        println!("    Min:    {:>10.2} μs", self.min_duration.as_secs_f64() * 1e6);
+        println!("    Max:    {:>10.2} μs", self.max_duration.as_secs_f64() * 1e6);
         println!("    StdDev: {:>10.2} μs", self.std_deviation);
         println!("    Iterations: {}", self.iterations);
+        if self.calls_per_sample > 1 {
+            println!("    Calls/Sample: {}", self.calls_per_sample);
+        }
+        if let Some(cycles) = &self.cycles {
+            println!("    Counter: {:>9.0} {}/op ({})", cycles.mean_per_op, cycles.source.unit(), cycles.source.label());
+        }
     }
-    
+
     pub fn mean_micros(&self) -> u64 {
         self.mean_duration.as_micros() as u64
     }
 
-    pub fn to_metric(&self, name: String, operation: String, extra_info: std::collections::HashMap<String, String>) -> BenchmarkMetric {
+    pub fn to_metric(&self, name: String, operation: String, mut extra_info: HashMap<String, String>) -> BenchmarkMetric {
+        extra_info.extend(self.notes.clone());
+
         BenchmarkMetric {
             name,
             operation,
-            mean_micros: self.mean_duration.as_micros() as f64,
-            min_micros: self.min_duration.as_micros() as f64,
-            max_micros: self.max_duration.as_micros() as f64,
+            mean_micros: self.mean_duration.as_secs_f64() * 1e6,
+            min_micros: self.min_duration.as_secs_f64() * 1e6,
+            max_micros: self.max_duration.as_secs_f64() * 1e6,
             std_dev_micros: self.std_deviation,
             iterations: self.iterations,
+            calls_per_sample: self.calls_per_sample,
+            timer_overhead_nanos: self.timer_overhead.as_secs_f64() * 1e9,
+            cycles: self.cycles.clone(),
+            hardware_counters: self.hardware_counters.clone(),
+            allocations: self.allocations.clone(),
+            peak_stack_bytes: self.peak_stack_bytes,
+            peak_stack_saturated: self.peak_stack_saturated,
+            message_size: None,
+            bytes_per_sec: None,
+            implementation: None,
+            sizes: Default::default(),
+            relative: None,
             extra_info,
         }
     }
 }
 
+/// Karakteristik `Instant::now()` di mesin ini, diukur sekali per proses
+#[derive(Debug, Clone, Copy)]
+pub struct TimerCalibration {
+    /// Median biaya satu pasang `Instant::now()` + `elapsed()`
+    pub overhead: Duration,
+    /// Selisih non-zero terkecil antara dua pembacaan timer berturut-turut
+    pub resolution: Duration,
+}
+
+/// Kalibrasi timer (cached). Dipakai untuk menentukan ukuran batch dan
+/// overhead yang dikurangkan dari setiap sample.
+pub fn timer_calibration() -> TimerCalibration {
+    static CALIBRATION: OnceLock<TimerCalibration> = OnceLock::new();
+    *CALIBRATION.get_or_init(calibrate_timer)
+}
+
+fn calibrate_timer() -> TimerCalibration {
+    let mut overheads = Vec::with_capacity(TIMER_CALIBRATION_ROUNDS);
+    let mut resolution = Duration::MAX;
+
+    for _ in 0..TIMER_CALIBRATION_ROUNDS {
+        let start = Instant::now();
+        overheads.push(start.elapsed());
+
+        // Tunggu sampai timer berubah untuk mendapatkan resolusinya
+        let first = Instant::now();
+        let mut next = Instant::now();
+        while next == first {
+            next = Instant::now();
+        }
+        resolution = resolution.min(next - first);
+    }
+
+    overheads.sort_unstable();
+
+    TimerCalibration {
+        overhead: overheads[overheads.len() / 2],
+        resolution,
+    }
+}
+
+/// Tentukan berapa kali `f` dipanggil per sample supaya satu sample jauh
+/// lebih panjang daripada resolusi timer. Operasi lambat tetap 1 call/sample.
+fn calibrate_calls_per_sample<F, R>(f: &mut F, calibration: &TimerCalibration) -> usize
+where
+    F: FnMut() -> R,
+{
+    let target = (calibration.resolution * TIMER_RESOLUTION_MULTIPLE)
+        .max(Duration::from_nanos(MIN_SAMPLE_TIME_NS));
+
+    let mut calls = 1;
+    loop {
+        let start = Instant::now();
+        for _ in 0..calls {
+            black_box(black_box(&mut *f)());
+        }
+        let elapsed = start.elapsed();
+
+        if elapsed >= target || calls >= MAX_CALLS_PER_SAMPLE {
+            return calls;
+        }
+
+        // Scale proporsional, tapi dibatasi 2x..10x per langkah
+        let scale = if elapsed.is_zero() {
+            10.0
+        } else {
+            (target.as_secs_f64() / elapsed.as_secs_f64()).clamp(2.0, 10.0)
+        };
+        calls = ((calls as f64 * scale).ceil() as usize).min(MAX_CALLS_PER_SAMPLE);
+    }
+}
+
 /// Accurate micro-benchmark dengan statistical analysis
 /// Lebih reliable daripada single-run measurement
-pub fn benchmark_operation<F, R>(mut f: F, iterations: usize) -> BenchmarkResult
+///
+/// Operasi yang lebih cepat dari resolusi timer diukur secara batched:
+/// setiap sample menjalankan `calls_per_sample` pemanggilan, overhead timer
+/// dikurangkan, lalu hasilnya dibagi per operasi. `iterations` adalah jumlah
+/// sample; statistik (mean/min/max/stddev) selalu dalam satuan per operasi.
+///
+/// Closure dan hasilnya dilewatkan melalui `black_box`, sehingga optimizer
+/// tidak bisa menganggap input yang di-capture konstan atau membuang hasil
+/// yang tidak dipakai. Closure sebaiknya me-return hasil operasinya.
+pub fn benchmark_operation<F, R>(f: F, iterations: usize) -> BenchmarkResult
 where
     F: FnMut() -> R,
 {
-    let mut durations = Vec::with_capacity(iterations);
-    
+    run_benchmark(f, iterations, &MeasurementConfig::default())
+}
+
+/// Sama seperti `benchmark_operation`, dengan pengukuran opsional dari `config`.
+/// Closure harus `Send` karena pengukuran stack berjalan di thread terpisah.
+pub fn benchmark_operation_with<F, R>(mut f: F, iterations: usize, config: &MeasurementConfig) -> BenchmarkResult
+where
+    F: FnMut() -> R + Send,
+{
+    let stack_usage = if config.measure_stack {
+        crate::stack::measure_stack_usage(&mut f)
+    } else {
+        None
+    };
+
+    let mut result = run_benchmark(f, iterations, config);
+    result.peak_stack_bytes = stack_usage.map(|usage| usage.bytes);
+    result.peak_stack_saturated = stack_usage.is_some_and(|usage| usage.saturated);
+    if config.measure_stack && stack_usage.is_none() {
+        result.notes.insert("stack".to_string(), "unavailable: failed to spawn probe thread".to_string());
+    }
+    result
+}
+
+fn run_benchmark<F, R>(mut f: F, iterations: usize, config: &MeasurementConfig) -> BenchmarkResult
+where
+    F: FnMut() -> R,
+{
+    let iterations = config.iterations.unwrap_or(iterations).max(1);
+    let calibration = timer_calibration();
+    let mut per_call_nanos = Vec::with_capacity(iterations);
+
+    // Counter dibuka per benchmark karena perf_event terikat ke thread pemanggil
+    let cycle_counter = if config.count_cycles { CycleCounter::new() } else { None };
+    let mut per_call_cycles = Vec::with_capacity(if cycle_counter.is_some() { iterations } else { 0 });
+
+    #[cfg(target_os = "linux")]
+    let hardware_counters = config.hardware_counters.then(crate::perf::HardwareCounters::open);
+
     // Warmup - penting untuk cache warming
     for _ in 0..10.min(iterations / 10) {
-        let _ = f();
+        black_box(black_box(&mut f)());
+    }
+
+    let calls_per_sample = calibrate_calls_per_sample(&mut f, &calibration);
+
+    // Actual measurements. Perf counters membungkus seluruh sample group,
+    // jadi pembacaan timer ikut terhitung (diamortisasi oleh batching).
+    #[cfg(target_os = "linux")]
+    if let Some(hw) = &hardware_counters {
+        hw.start();
     }
-    
-    // Actual measurements
+
     for _ in 0..iterations {
+        let start_cycles = cycle_counter.as_ref().map(|c| c.read());
         let start = Instant::now();
-        let _ = f();
-        let duration = start.elapsed();
-        durations.push(duration);
+        for _ in 0..calls_per_sample {
+            black_box(black_box(&mut f)());
+        }
+        let duration = start.elapsed().saturating_sub(calibration.overhead);
+        if let (Some(counter), Some(start_cycles)) = (&cycle_counter, start_cycles) {
+            let cycles = counter.read().saturating_sub(start_cycles).saturating_sub(counter.overhead());
+            per_call_cycles.push(cycles as f64 / calls_per_sample as f64);
+        }
+        per_call_nanos.push(duration.as_secs_f64() * 1e9 / calls_per_sample as f64);
+    }
+
+    #[cfg(target_os = "linux")]
+    let (hardware_counters, perf_note) = match &hardware_counters {
+        Some(hw) => (hw.stop((iterations * calls_per_sample) as u64), hw.note()),
+        None => (None, None),
+    };
+    #[cfg(not(target_os = "linux"))]
+    let (hardware_counters, perf_note) = (
+        None,
+        config.hardware_counters.then(|| "unavailable: perf counters require Linux".to_string()),
+    );
+
+    let mut notes = HashMap::new();
+    if let Some(note) = perf_note {
+        notes.insert("perf_counters".to_string(), note);
+    }
+
+    // Alokasi diukur di pass terpisah (tidak di-timing)
+    let allocations = if config.track_allocations {
+        let allocations = measure_allocations(&mut f);
+        if allocations.is_none() {
+            notes.insert(
+                "allocations".to_string(),
+                "unavailable: build with --features alloc-tracking".to_string(),
+            );
+        }
+        allocations
+    } else {
+        None
+    };
+
+    let cycles = cycle_counter.map(|counter| CycleStats {
+        mean_per_op: per_call_cycles.iter().sum::<f64>() / iterations as f64,
+        min_per_op: per_call_cycles.iter().copied().fold(f64::INFINITY, f64::min),
+        source: counter.source(),
+    });
+
+    BenchmarkResult {
+        cycles,
+        hardware_counters,
+        allocations,
+        notes,
+        ..summarize(&per_call_nanos, calls_per_sample, calibration.overhead)
     }
-    
+}
+
+/// Statistik dasar dari durasi per operasi (nanodetik); field opsional kosong
+fn summarize(per_call_nanos: &[f64], calls_per_sample: usize, timer_overhead: Duration) -> BenchmarkResult {
+    let iterations = per_call_nanos.len();
+
     // Calculate statistics
-    let total: Duration = durations.iter().sum();
-    let mean = total / iterations as u32;
-    
-    let min = *durations.iter().min().unwrap();
-    let max = *durations.iter().max().unwrap();
-    
-    // Standard deviation
-    let mean_micros = mean.as_micros() as f64;
-    let variance: f64 = durations
+    let mean_nanos = per_call_nanos.iter().sum::<f64>() / iterations as f64;
+    let min_nanos = per_call_nanos.iter().copied().fold(f64::INFINITY, f64::min);
+    let max_nanos = per_call_nanos.iter().copied().fold(0.0, f64::max);
+
+    // Standard deviation (dalam μs)
+    let variance: f64 = per_call_nanos
         .iter()
-        .map(|d| {
-            let diff = d.as_micros() as f64 - mean_micros;
+        .map(|n| {
+            let diff = (n - mean_nanos) / 1000.0;
             diff * diff
         })
         .sum::<f64>()
         / iterations as f64;
     let std_deviation = variance.sqrt();
-    
+
     BenchmarkResult {
-        mean_duration: mean,
-        min_duration: min,
-        max_duration: max,
+        mean_duration: Duration::from_secs_f64(mean_nanos / 1e9),
+        min_duration: Duration::from_secs_f64(min_nanos / 1e9),
+        max_duration: Duration::from_secs_f64(max_nanos / 1e9),
         std_deviation,
         iterations,
+        calls_per_sample,
+        timer_overhead,
+        cycles: None,
+        hardware_counters: None,
+        allocations: None,
+        peak_stack_bytes: None,
+        peak_stack_saturated: false,
+        notes: HashMap::new(),
     }
 }
 
+/// Cold-cache benchmark: tanpa warmup dan tanpa batching. Sebelum setiap
+/// sample, cache dikosongkan dengan `CacheEvictor` dan input diganti ke set
+/// berikutnya dari `sets`, sehingga key, pesan dan signature tidak lagi ada
+/// di cache maupun branch predictor. Hanya wall-clock time yang diukur;
+/// dari `config` hanya `iterations` yang dipakai.
+pub fn benchmark_operation_cold<S, F, R>(sets: &[S], mut f: F, iterations: usize, config: &MeasurementConfig) -> BenchmarkResult
+where
+    F: FnMut(&S) -> R,
+{
+    assert!(!sets.is_empty(), "cold benchmark needs at least one input set");
+    let iterations = config.iterations.unwrap_or(iterations).max(1);
+    let calibration = timer_calibration();
+    let mut evictor = crate::cache::CacheEvictor::new(CACHE_EVICTION_BYTES);
+    let mut per_call_nanos = Vec::with_capacity(iterations);
+
+    for i in 0..iterations {
+        let set = &sets[i % sets.len()];
+        evictor.evict();
+
+        let start = Instant::now();
+        black_box(f(black_box(set)));
+        let duration = start.elapsed().saturating_sub(calibration.overhead);
+        per_call_nanos.push(duration.as_secs_f64() * 1e9);
+    }
+
+    summarize(&per_call_nanos, 1, calibration.overhead)
+}
+
+/// Hitung alokasi heap dan puncak live bytes per pemanggilan `f`.
+/// `None` jika `CountingAllocator` tidak terpasang.
+fn measure_allocations<F, R>(f: &mut F) -> Option<AllocationStats>
+where
+    F: FnMut() -> R,
+{
+    if !crate::alloc::is_installed() {
+        return None;
+    }
+
+    let mut allocations = 0;
+    let mut bytes_allocated = 0;
+    let mut peak_live_bytes = 0;
+    for _ in 0..ALLOCATION_SAMPLE_CALLS {
+        let (_, delta) = crate::alloc::track(|| black_box(black_box(&mut *f)()));
+        allocations += delta.allocations;
+        bytes_allocated += delta.bytes_allocated;
+        peak_live_bytes = peak_live_bytes.max(delta.peak_live_bytes);
+    }
+
+    Some(AllocationStats {
+        allocations_per_op: allocations as f64 / ALLOCATION_SAMPLE_CALLS as f64,
+        bytes_per_op: bytes_allocated as f64 / ALLOCATION_SAMPLE_CALLS as f64,
+        peak_live_bytes,
+    })
+}
+
 /// Quick benchmark untuk operasi yang sangat cepat
 pub fn quick_benchmark<F, R>(f: F) -> Duration
 where
     F: FnOnce() -> R,
 {
     let start = Instant::now();
-    let _ = f();
+    black_box(f());
     start.elapsed()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fast_operation_is_batched() {
+        let mut counter = 0u64;
+        let result = benchmark_operation(
+            || {
+                counter = black_box(counter.wrapping_add(1));
+                counter
+            },
+            20,
+        );
+        assert!(result.calls_per_sample > 1, "fast operation should be batched");
+        assert!(result.min_duration <= result.mean_duration);
+        assert!(result.mean_duration <= result.max_duration);
+    }
+
+    // Closure sendiri tidak memakai black_box: hanya harness yang menjaga
+    // hasilnya. Crate ini di-build dengan opt-level 3 di profile test (lihat
+    // Cargo.toml), jadi tanpa black_box di harness komputasi ini dihapus
+    // sebagai dead code.
+    #[test]
+    fn test_discarded_result_is_not_optimized_away() {
+        // Seed ditangkap closure, yang dilewatkan harness lewat black_box,
+        // jadi hasilnya tidak bisa dihitung saat compile
+        let seed = 0x9E37_79B9_7F4A_7C15u64;
+        let result = benchmark_operation(
+            move || (0..10_000u64).fold(seed, |acc, x| acc.wrapping_mul(31).wrapping_add(x)),
+            20,
+        );
+        assert!(
+            result.mean_duration >= Duration::from_micros(1),
+            "discarded computation took only {:?}",
+            result.mean_duration
+        );
+    }
+
+    #[test]
+    fn test_cycle_counting_is_opt_in() {
+        let op = || (0..1_000u64).fold(1u64, |acc, x| acc.wrapping_mul(31).wrapping_add(x));
+        assert!(benchmark_operation(op, 10).cycles.is_none());
+
+        let config = MeasurementConfig { count_cycles: true, ..Default::default() };
+        let result = benchmark_operation_with(op, 10, &config);
+        if CycleCounter::new().is_some() {
+            let cycles = result.cycles.expect("cycle stats missing");
+            assert!(cycles.mean_per_op > 0.0);
+            assert!(cycles.min_per_op <= cycles.mean_per_op);
+        }
+    }
+
+    #[test]
+    fn test_unavailable_perf_counters_degrade_gracefully() {
+        let config = MeasurementConfig { hardware_counters: true, ..Default::default() };
+        let result = benchmark_operation_with(|| black_box(42u64).count_ones(), 10, &config);
+
+        // Salah satu dari keduanya harus ada: angka counter atau alasan tidak tersedia
+        let stats = result.hardware_counters.clone().unwrap_or_default();
+        let note = result.notes.get("perf_counters");
+        assert!(stats.instructions_per_op.is_some() || note.is_some());
+        let metric = result.to_metric("Test".to_string(), "Op".to_string(), Default::default());
+        assert_eq!(metric.extra_info.get("perf_counters"), note);
+    }
+
+    #[test]
+    fn test_cold_benchmark_rotates_input_sets() {
+        let sets = [1u64, 2, 3];
+        let mut seen = Vec::new();
+        let result = benchmark_operation_cold(&sets, |set| seen.push(*set), 6, &MeasurementConfig::default());
+        assert_eq!(seen, vec![1, 2, 3, 1, 2, 3]);
+        assert_eq!(result.calls_per_sample, 1);
+        assert_eq!(result.iterations, 6);
+
+        // `-n` berlaku juga untuk cold, dan 0 iterasi tetap minimal satu sample
+        let config = MeasurementConfig { iterations: Some(4), ..Default::default() };
+        assert_eq!(benchmark_operation_cold(&sets, |set| *set, 6, &config).iterations, 4);
+        assert_eq!(benchmark_operation_cold(&sets, |set| *set, 0, &MeasurementConfig::default()).iterations, 1);
+    }
+
+    #[test]
+    fn test_algorithm_filter_ignores_case_and_punctuation() {
+        let config = MeasurementConfig { algorithms: vec!["ml-kem".to_string(), "P256".to_string()], ..Default::default() };
+        assert!(config.includes("Kyber (ML-KEM-512)"));
+        assert!(config.includes("ECDH (P-256)"));
+        assert!(!config.includes("Ed25519"));
+        assert!(MeasurementConfig::default().includes("Ed25519"));
+    }
+
+    #[test]
+    fn test_slow_operation_is_timed_per_call() {
+        let result = benchmark_operation(
+            || std::thread::sleep(Duration::from_millis(1)),
+            5,
+        );
+        assert_eq!(result.calls_per_sample, 1);
+        assert!(result.mean_duration >= Duration::from_millis(1));
+    }
+}
@@ -1,6 +1,15 @@
+use std::fmt;
+use schemars::JsonSchema;
 use serde::{Serialize, Deserialize};
+use crate::constants::{REFERENCE_KEM_ALGORITHM, REFERENCE_SIGNATURE_ALGORITHM, REPORT_SCHEMA_VERSION};
+use crate::cycles::CycleSource;
+use crate::measurement::MeasurementConfig;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+fn default_calls_per_sample() -> usize {
+    1
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct BenchmarkMetric {
     pub name: String,
     pub operation: String,
@@ -9,13 +18,431 @@ pub struct BenchmarkMetric {
     pub max_micros: f64,
     pub std_dev_micros: f64,
     pub iterations: usize,
+    /// Operations per timed sample; statistics above are already per operation
+    #[serde(default = "default_calls_per_sample")]
+    pub calls_per_sample: usize,
+    /// Timer overhead subtracted from every sample
+    #[serde(default)]
+    pub timer_overhead_nanos: f64,
+    /// Cycles per operation, only when cycle counting is enabled
+    #[serde(default)]
+    pub cycles: Option<CycleStats>,
+    /// Linux perf counters per operation, only when enabled and available
+    #[serde(default)]
+    pub hardware_counters: Option<HardwareCounterStats>,
+    /// Heap allocations per operation, only with the counting allocator installed
+    #[serde(default)]
+    pub allocations: Option<AllocationStats>,
+    /// Stack high-water mark of a single operation, only when enabled
+    #[serde(default)]
+    pub peak_stack_bytes: Option<usize>,
+    /// The whole painted area was used: peak_stack_bytes is only a lower bound
+    #[serde(default)]
+    pub peak_stack_saturated: bool,
+    /// Signed message length, only set by the message-size sweep
+    #[serde(default)]
+    pub message_size: Option<usize>,
+    /// message_size / mean latency
+    #[serde(default)]
+    pub bytes_per_sec: Option<f64>,
+    /// Library providing the algorithm, e.g. "pqcrypto" or "RustCrypto"
+    #[serde(default)]
+    pub implementation: Option<String>,
+    /// Key, signature, ciphertext or encoding sizes of the measured algorithm
+    #[serde(default)]
+    pub sizes: ArtifactSizes,
+    /// Mean latency relative to the reference algorithm, see `normalize`
+    #[serde(default)]
+    pub relative: Option<RelativePerformance>,
+    /// Free-form notes, e.g. why an optional measurement was unavailable
     pub extra_info: std::collections::HashMap<String, String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl BenchmarkMetric {
+    pub fn with_implementation(mut self, implementation: &str) -> Self {
+        self.implementation = Some(implementation.to_string());
+        self
+    }
+
+    pub fn with_sizes(mut self, sizes: ArtifactSizes) -> Self {
+        self.sizes = sizes;
+        self
+    }
+
+    /// Fixture unit test: min = max = mean, field lain default
+    #[cfg(test)]
+    pub(crate) fn for_test(name: &str, operation: &str, mean: f64) -> Self {
+        BenchmarkMetric {
+            name: name.to_string(),
+            operation: operation.to_string(),
+            mean_micros: mean,
+            min_micros: mean,
+            max_micros: mean,
+            iterations: 100,
+            calls_per_sample: 1,
+            ..Default::default()
+        }
+    }
+}
+
+/// This metric's mean divided by the reference metric's mean
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct RelativePerformance {
+    pub reference: String,
+    pub reference_operation: String,
+    /// 1.0 = as fast as the reference, 2.0 = twice as slow
+    pub factor: f64,
+}
+
+/// Reference algorithm per algorithm kind used for `relative` factors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ReferenceAlgorithms {
+    pub signature: String,
+    pub kem: String,
+}
+
+impl Default for ReferenceAlgorithms {
+    fn default() -> Self {
+        ReferenceAlgorithms {
+            signature: REFERENCE_SIGNATURE_ALGORITHM.to_string(),
+            kem: REFERENCE_KEM_ALGORITHM.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SizeUnit {
+    Bytes,
+    Bits,
+}
+
+/// An integer size with its unit; RSA key sizes are in bits, everything else in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Size {
+    pub value: u64,
+    pub unit: SizeUnit,
+}
+
+impl Size {
+    pub fn bytes(value: usize) -> Self {
+        Size { value: value as u64, unit: SizeUnit::Bytes }
+    }
+
+    pub fn bits(value: usize) -> Self {
+        Size { value: value as u64, unit: SizeUnit::Bits }
+    }
+}
+
+impl fmt::Display for Size {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let unit = match self.unit {
+            SizeUnit::Bytes => "bytes",
+            SizeUnit::Bits => "bits",
+        };
+        write!(f, "{} {}", self.value, unit)
+    }
+}
+
+/// Sizes that apply to a metric; absent ones are omitted from the JSON
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ArtifactSizes {
+    /// Key size where public and secret key share one figure (classical algorithms)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<Size>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<Size>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub secret_key: Option<Size>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<Size>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ciphertext: Option<Size>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shared_secret: Option<Size>,
+    /// Length of the serialized form, set by the serialization benchmarks
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub encoded: Option<Size>,
+}
+
+impl ArtifactSizes {
+    /// Display labels in field order
+    pub const LABELS: [&'static str; 7] = [
+        "Key Size",
+        "Public Key Size",
+        "Secret Key Size",
+        "Signature Size",
+        "Ciphertext Size",
+        "Shared Secret Size",
+        "Encoded Size",
+    ];
+
+    /// (label, size) of every size that is set, in field order
+    pub fn entries(&self) -> Vec<(&'static str, Size)> {
+        let sizes = [
+            self.key,
+            self.public_key,
+            self.secret_key,
+            self.signature,
+            self.ciphertext,
+            self.shared_secret,
+            self.encoded,
+        ];
+        Self::LABELS
+            .into_iter()
+            .zip(sizes)
+            .filter_map(|(label, size)| size.map(|size| (label, size)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CycleStats {
+    /// In units of `source` (TSC/timer ticks or core cycles)
+    pub mean_per_op: f64,
+    pub min_per_op: f64,
+    pub source: CycleSource,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct HardwareCounterStats {
+    pub instructions_per_op: Option<f64>,
+    pub branch_misses_per_op: Option<f64>,
+    pub cache_misses_per_op: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct AllocationStats {
+    pub allocations_per_op: f64,
+    pub bytes_per_op: f64,
+    pub peak_live_bytes: usize,
+}
+
+/// CPU time and RSS of this process over one algorithm's benchmark window
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ResourceUsage {
+    pub algorithm: String,
+    pub wall_time_secs: f64,
+    /// User/system split is only available on Unix (getrusage)
+    pub cpu_user_secs: Option<f64>,
+    pub cpu_system_secs: Option<f64>,
+    pub cpu_total_secs: f64,
+    pub cpu_utilization_percent: f64,
+    pub rss_start_bytes: u64,
+    pub rss_end_bytes: u64,
+    pub rss_peak_bytes: u64,
+}
+
+/// Operations per second of one operation at a given thread count
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ThroughputResult {
+    pub name: String,
+    pub operation: String,
+    pub threads: usize,
+    pub duration_secs: f64,
+    pub total_ops: u64,
+    pub ops_per_sec: f64,
+    pub ops_per_sec_per_thread: f64,
+    /// ops_per_sec / (threads × single-thread ops_per_sec); 1.0 is linear scaling
+    pub scaling_efficiency: f64,
+}
+
+/// Warm-cache and cold-cache figures for the same operation
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CacheComparison {
+    pub name: String,
+    pub operation: String,
+    pub warm: BenchmarkMetric,
+    pub cold: BenchmarkMetric,
+    /// cold mean / warm mean
+    pub cold_to_warm_ratio: f64,
+}
+
+/// Verification with one reused key vs rotating across a pool of keypairs
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct KeyPoolComparison {
+    pub name: String,
+    pub pool_size: usize,
+    pub single_key: BenchmarkMetric,
+    pub key_pool: BenchmarkMetric,
+    /// (key pool mean / single-key mean - 1) × 100
+    pub difference_percent: f64,
+}
+
+/// Ed25519 batch verification at one batch size
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BatchVerifyResult {
+    pub batch_size: usize,
+    /// Whole-batch timing; mean is per batch, not per signature
+    pub batch: BenchmarkMetric,
+    pub per_signature_micros: f64,
+    pub single_verify_micros: f64,
+    /// single verify / amortized per-signature cost
+    pub speedup: f64,
+}
+
+/// Whether output of one implementation is accepted by another
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct InteropCheck {
+    pub algorithm: String,
+    pub direction: String,
+    pub passed: bool,
+    pub detail: Option<String>,
+}
+
+/// Environment findings from the optional pre-flight step
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PreflightReport {
+    pub pinned_core: Option<usize>,
+    pub governor: Option<String>,
+    pub turbo_enabled: Option<bool>,
+    pub load_average_1m: Option<f64>,
+    pub logical_cpus: usize,
+    pub busy_processes: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Machine, toolchain and configuration a report was produced with
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnvironmentMetadata {
+    /// RFC 3339 UTC time the run started
+    pub timestamp: String,
+    pub timestamp_unix: u64,
+    /// Omitted with `--no-hostname`
+    pub hostname: Option<String>,
+    pub os: Option<String>,
+    pub os_version: Option<String>,
+    pub kernel_version: Option<String>,
+    pub arch: String,
+    pub cpu_model: Option<String>,
+    pub cpu_vendor: Option<String>,
+    pub physical_cores: Option<usize>,
+    pub logical_cpus: usize,
+    /// Current (not maximum) clock of the first CPU as reported by the OS
+    pub cpu_frequency_mhz: Option<u64>,
+    pub governor: Option<String>,
+    pub turbo_enabled: Option<bool>,
+    pub total_memory_bytes: u64,
+    pub rustc_version: String,
+    pub target: String,
+    pub profile: String,
+    pub opt_level: String,
+    pub target_features: Vec<String>,
+    pub crate_version: String,
+    /// Direct dependency versions from Cargo.lock at build time
+    pub dependencies: std::collections::BTreeMap<String, String>,
+    /// Cargo features compiled into this binary
+    pub features: Vec<String>,
+    /// Where key material and random inputs come from
+    pub rng_source: String,
+    /// Always `None` today: keys come from the OS RNG, which cannot be seeded
+    pub seed: Option<u64>,
+    /// Command line the report was produced with
+    pub arguments: Vec<String>,
+    pub measurement: MeasurementConfig,
+}
+
+/// Version of the report layout; new reports always carry the current one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, JsonSchema)]
+#[serde(transparent)]
+pub struct SchemaVersion(pub u32);
+
+impl Default for SchemaVersion {
+    fn default() -> Self {
+        SchemaVersion(REPORT_SCHEMA_VERSION)
+    }
+}
+
+/// Serialized as `schema_version`; load older files with `schema::load_report`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct BenchmarkReport {
+    pub schema_version: SchemaVersion,
     pub signatures: Vec<BenchmarkMetric>,
     pub kem: Vec<BenchmarkMetric>,
     pub keygen_time_secs: f64,
     pub total_time_secs: f64,
+    #[serde(default)]
+    pub resources: Vec<ResourceUsage>,
+    #[serde(default)]
+    pub throughput: Vec<ThroughputResult>,
+    #[serde(default)]
+    pub message_sweep: Vec<BenchmarkMetric>,
+    #[serde(default)]
+    pub preflight: Option<PreflightReport>,
+    #[serde(default)]
+    pub cache_modes: Vec<CacheComparison>,
+    #[serde(default)]
+    pub key_pool: Vec<KeyPoolComparison>,
+    #[serde(default)]
+    pub batch_verify: Vec<BatchVerifyResult>,
+    #[serde(default)]
+    pub serialization: Vec<BenchmarkMetric>,
+    #[serde(default)]
+    pub implementations: Vec<BenchmarkMetric>,
+    #[serde(default)]
+    pub interop: Vec<InteropCheck>,
+    /// Key generation time per algorithm (`keygen` subcommand)
+    #[serde(default)]
+    pub keygen: Vec<BenchmarkMetric>,
+    #[serde(default)]
+    pub environment: Option<EnvironmentMetadata>,
+    /// References behind the metrics' `relative` factors, if normalized
+    #[serde(default)]
+    pub references: Option<ReferenceAlgorithms>,
+}
+
+impl BenchmarkReport {
+    /// Every latency metric in the report with its section name, in report order
+    pub fn metrics(&self) -> Vec<(&'static str, &BenchmarkMetric)> {
+        let mut metrics: Vec<(&'static str, &BenchmarkMetric)> = Vec::new();
+        for (section, list) in [
+            ("signatures", &self.signatures),
+            ("kem", &self.kem),
+            ("keygen", &self.keygen),
+            ("message_sweep", &self.message_sweep),
+            ("serialization", &self.serialization),
+            ("implementations", &self.implementations),
+        ] {
+            metrics.extend(list.iter().map(|m| (section, m)));
+        }
+        for comparison in &self.cache_modes {
+            metrics.push(("cache_warm", &comparison.warm));
+            metrics.push(("cache_cold", &comparison.cold));
+        }
+        for comparison in &self.key_pool {
+            metrics.push(("key_pool", &comparison.single_key));
+            metrics.push(("key_pool", &comparison.key_pool));
+        }
+        for result in &self.batch_verify {
+            metrics.push(("batch_verify", &result.batch));
+        }
+        metrics
+    }
+
+    /// Same order as `metrics`
+    pub fn metrics_mut(&mut self) -> Vec<(&'static str, &mut BenchmarkMetric)> {
+        let mut metrics: Vec<(&'static str, &mut BenchmarkMetric)> = Vec::new();
+        for (section, list) in [
+            ("signatures", &mut self.signatures),
+            ("kem", &mut self.kem),
+            ("keygen", &mut self.keygen),
+            ("message_sweep", &mut self.message_sweep),
+            ("serialization", &mut self.serialization),
+            ("implementations", &mut self.implementations),
+        ] {
+            metrics.extend(list.iter_mut().map(|m| (section, m)));
+        }
+        for comparison in &mut self.cache_modes {
+            metrics.push(("cache_warm", &mut comparison.warm));
+            metrics.push(("cache_cold", &mut comparison.cold));
+        }
+        for comparison in &mut self.key_pool {
+            metrics.push(("key_pool", &mut comparison.single_key));
+            metrics.push(("key_pool", &mut comparison.key_pool));
+        }
+        for result in &mut self.batch_verify {
+            metrics.push(("batch_verify", &mut result.batch));
+        }
+        metrics
+    }
 }
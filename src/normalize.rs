@@ -0,0 +1,173 @@
+// Normalisasi terhadap algoritma acuan ("berapa kali lebih lambat dari Ed25519?")
+//
+// Jenis algoritma (signature/KEM) diambil dari katalog, lalu metric acuan
+// dicari dengan operasi dan ukuran pesan yang sama: pertama di section yang
+// sama, lalu di signatures/kem/keygen. Operasi KEM tanpa pasangan yang sama
+// namanya (Encapsulate/Decapsulate vs Key Exchange X25519) dibandingkan
+// dengan satu-satunya operasi non-keygen acuan, hanya di section KEM (kem,
+// cache dan implementations); metric lain tanpa pasangan tidak diberi faktor.
+use crate::catalog::{self, AlgorithmKind};
+use crate::models::{BenchmarkMetric, BenchmarkReport, ReferenceAlgorithms, RelativePerformance};
+
+const KEYGEN_OPERATION: &str = "Keygen";
+const KEM_OPERATIONS: [&str; 2] = ["Encapsulate", "Decapsulate"];
+/// Section yang bisa berisi operasi KEM dari suite utama
+const KEM_SECTIONS: [&str; 4] = ["kem", "cache_warm", "cache_cold", "implementations"];
+
+fn normalize_name(s: &str) -> String {
+    s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect()
+}
+
+/// Nama katalog untuk `pattern` (cocok seperti `--algorithm`), hanya dari `kind`
+pub fn resolve_reference(pattern: &str, kind: AlgorithmKind) -> Option<String> {
+    let pattern = normalize_name(pattern);
+    let candidates: Vec<String> = catalog::catalog()
+        .into_iter()
+        .filter(|info| info.kind == kind)
+        .map(|info| info.name)
+        .collect();
+    // Nama persis didahulukan supaya "ECDH (P-256)" tidak kalah oleh substring
+    candidates
+        .iter()
+        .find(|name| normalize_name(name) == pattern)
+        .or_else(|| candidates.iter().find(|name| normalize_name(name).contains(&pattern)))
+        .cloned()
+}
+
+fn kind_of(section: &str, name: &str) -> Option<AlgorithmKind> {
+    if let Some(info) = catalog::catalog().into_iter().find(|info| info.name == name) {
+        return Some(info.kind);
+    }
+    match section {
+        "signatures" => Some(AlgorithmKind::Signature),
+        "kem" => Some(AlgorithmKind::Kem),
+        _ => None,
+    }
+}
+
+fn reference_for<'a>(
+    metrics: &[(&'static str, &'a BenchmarkMetric)],
+    section: &str,
+    metric: &BenchmarkMetric,
+    reference: &str,
+) -> Option<&'a BenchmarkMetric> {
+    let in_section = |s: &str| -> Vec<&'a BenchmarkMetric> {
+        metrics.iter().filter(|(sec, m)| *sec == s && m.name == reference).map(|(_, m)| *m).collect()
+    };
+
+    for candidate_section in [section, "signatures", "kem", "keygen"] {
+        let exact = in_section(candidate_section)
+            .into_iter()
+            .find(|m| m.operation == metric.operation && m.message_size == metric.message_size);
+        if exact.is_some() {
+            return exact;
+        }
+    }
+
+    if !KEM_OPERATIONS.contains(&metric.operation.as_str()) || !KEM_SECTIONS.contains(&section) {
+        return None;
+    }
+    for candidate_section in [section, "kem"] {
+        if let [only] = in_section(candidate_section)
+            .into_iter()
+            .filter(|m| m.operation != KEYGEN_OPERATION)
+            .collect::<Vec<_>>()[..]
+        {
+            return Some(only);
+        }
+    }
+    None
+}
+
+/// Isi `relative` untuk setiap metric yang punya acuan di report ini dan
+/// catat acuannya di `report.references`
+pub fn normalize(report: &mut BenchmarkReport, references: &ReferenceAlgorithms) {
+    let factors: Vec<Option<RelativePerformance>> = {
+        let metrics = report.metrics();
+        metrics
+            .iter()
+            .map(|(section, metric)| {
+                let reference = match kind_of(section, &metric.name)? {
+                    AlgorithmKind::Signature => &references.signature,
+                    AlgorithmKind::Kem => &references.kem,
+                };
+                let base = reference_for(&metrics, section, metric, reference)?;
+                (base.mean_micros > 0.0).then(|| RelativePerformance {
+                    reference: base.name.clone(),
+                    reference_operation: base.operation.clone(),
+                    factor: metric.mean_micros / base.mean_micros,
+                })
+            })
+            .collect()
+    };
+    for ((_, metric), relative) in report.metrics_mut().into_iter().zip(factors) {
+        metric.relative = relative;
+    }
+    report.references = Some(references.clone());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::implementations::{DILITHIUM_NAME, KYBER_NAME};
+
+    #[test]
+    fn test_factors_against_default_references() {
+        let mut report = BenchmarkReport {
+            signatures: vec![
+                BenchmarkMetric::for_test("Ed25519", "Sign", 20.0),
+                BenchmarkMetric::for_test("Ed25519", "Verify", 40.0),
+                BenchmarkMetric::for_test(DILITHIUM_NAME, "Sign", 100.0),
+                BenchmarkMetric::for_test(DILITHIUM_NAME, "Verify", 20.0),
+            ],
+            kem: vec![
+                BenchmarkMetric::for_test("X25519 Diffie-Hellman", "Key Exchange", 50.0),
+                BenchmarkMetric::for_test(KYBER_NAME, "Encapsulate", 25.0),
+            ],
+            keygen: vec![
+                BenchmarkMetric::for_test("Ed25519", "Keygen", 10.0),
+                BenchmarkMetric::for_test(KYBER_NAME, "Keygen", 30.0),
+            ],
+            ..Default::default()
+        };
+        normalize(&mut report, &ReferenceAlgorithms::default());
+
+        let factor = |m: &BenchmarkMetric| m.relative.as_ref().map(|r| r.factor);
+        assert_eq!(factor(&report.signatures[0]), Some(1.0));
+        assert_eq!(factor(&report.signatures[2]), Some(5.0));
+        assert_eq!(factor(&report.signatures[3]), Some(0.5));
+        assert_eq!(factor(&report.kem[1]), Some(0.5));
+        assert_eq!(report.kem[1].relative.as_ref().unwrap().reference_operation, "Key Exchange");
+        // Tidak ada keygen X25519 di report, jadi ML-KEM keygen tanpa acuan
+        assert_eq!(factor(&report.keygen[1]), None);
+        assert_eq!(report.references, Some(ReferenceAlgorithms::default()));
+    }
+
+    #[test]
+    fn test_operation_fallback_only_for_kem_operations() {
+        let mut report = BenchmarkReport {
+            kem: vec![BenchmarkMetric::for_test("X25519 Diffie-Hellman", "Key Exchange", 50.0)],
+            serialization: vec![
+                BenchmarkMetric::for_test("X25519 Diffie-Hellman", "Decode Public Key", 1.0),
+                BenchmarkMetric::for_test(KYBER_NAME, "Decode Public Key", 2.0),
+                BenchmarkMetric::for_test(KYBER_NAME, "Decode Ciphertext", 3.0),
+            ],
+            implementations: vec![BenchmarkMetric::for_test(KYBER_NAME, "Decapsulate", 100.0)],
+            ..Default::default()
+        };
+        normalize(&mut report, &ReferenceAlgorithms::default());
+
+        let factor = |m: &BenchmarkMetric| m.relative.as_ref().map(|r| r.factor);
+        assert_eq!(factor(&report.serialization[1]), Some(2.0));
+        assert_eq!(factor(&report.serialization[2]), None);
+        assert_eq!(factor(&report.implementations[0]), Some(2.0));
+        assert_eq!(report.implementations[0].relative.as_ref().unwrap().reference_operation, "Key Exchange");
+    }
+
+    #[test]
+    fn test_resolve_reference() {
+        assert_eq!(resolve_reference("ecdsa", AlgorithmKind::Signature).as_deref(), Some("ECDSA P-256"));
+        assert_eq!(resolve_reference("x25519", AlgorithmKind::Kem).as_deref(), Some("X25519 Diffie-Hellman"));
+        assert_eq!(resolve_reference("ed25519", AlgorithmKind::Kem), None);
+    }
+}
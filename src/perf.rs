@@ -0,0 +1,250 @@
+// Minimal wrapper untuk Linux perf_event_open(2)
+//
+// libc hanya menyediakan nomor syscall, jadi struct `perf_event_attr` dan
+// konstanta yang dibutuhkan didefinisikan di sini (PERF_ATTR_SIZE_VER5).
+use std::io;
+use std::os::raw::{c_int, c_ulong};
+use crate::models::HardwareCounterStats;
+
+pub const PERF_TYPE_HARDWARE: u32 = 0;
+
+pub const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+pub const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+pub const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+pub const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+
+// read() mengembalikan value, time_enabled, time_running (untuk multiplexing)
+const PERF_FORMAT_TOTAL_TIME_ENABLED: u64 = 1 << 0;
+const PERF_FORMAT_TOTAL_TIME_RUNNING: u64 = 1 << 1;
+
+const PERF_EVENT_IOC_ENABLE: c_ulong = 0x2400;
+const PERF_EVENT_IOC_DISABLE: c_ulong = 0x2401;
+const PERF_EVENT_IOC_RESET: c_ulong = 0x2403;
+
+// Bit flags di `perf_event_attr.flags`
+const ATTR_FLAG_DISABLED: u64 = 1 << 0;
+const ATTR_FLAG_EXCLUDE_KERNEL: u64 = 1 << 5;
+const ATTR_FLAG_EXCLUDE_HV: u64 = 1 << 6;
+
+#[repr(C)]
+#[derive(Default)]
+struct PerfEventAttr {
+    type_: u32,
+    size: u32,
+    config: u64,
+    sample_period: u64,
+    sample_type: u64,
+    read_format: u64,
+    flags: u64,
+    wakeup_events: u32,
+    bp_type: u32,
+    config1: u64,
+    config2: u64,
+    branch_sample_type: u64,
+    sample_regs_user: u64,
+    sample_stack_user: u32,
+    clockid: i32,
+    sample_regs_intr: u64,
+    aux_watermark: u32,
+    sample_max_stack: u16,
+    reserved_2: u16,
+}
+
+/// Satu hardware counter untuk thread pemanggil (user space saja)
+#[derive(Debug)]
+pub struct PerfCounter {
+    fd: c_int,
+}
+
+impl PerfCounter {
+    /// Buka counter dan langsung aktifkan. Gagal jika kernel tidak mendukung
+    /// event tersebut atau `perf_event_paranoid` melarangnya.
+    pub fn open(type_: u32, config: u64) -> io::Result<Self> {
+        let attr = PerfEventAttr {
+            type_,
+            size: std::mem::size_of::<PerfEventAttr>() as u32,
+            config,
+            read_format: PERF_FORMAT_TOTAL_TIME_ENABLED | PERF_FORMAT_TOTAL_TIME_RUNNING,
+            flags: ATTR_FLAG_DISABLED | ATTR_FLAG_EXCLUDE_KERNEL | ATTR_FLAG_EXCLUDE_HV,
+            ..Default::default()
+        };
+
+        // pid = 0 (thread ini), cpu = -1 (semua CPU), tanpa group, tanpa flags
+        let fd = unsafe {
+            libc::syscall(
+                libc::SYS_perf_event_open,
+                &attr as *const PerfEventAttr,
+                0 as libc::pid_t,
+                -1 as c_int,
+                -1 as c_int,
+                0 as c_ulong,
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let counter = PerfCounter { fd: fd as c_int };
+        counter.reset()?;
+        counter.enable()?;
+        Ok(counter)
+    }
+
+    pub fn reset(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_RESET)
+    }
+
+    pub fn enable(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_ENABLE)
+    }
+
+    pub fn disable(&self) -> io::Result<()> {
+        self.ioctl(PERF_EVENT_IOC_DISABLE)
+    }
+
+    /// Nilai counter saat ini, di-scale jika kernel melakukan multiplexing
+    pub fn read(&self) -> io::Result<u64> {
+        let mut values = [0u64; 3];
+        let n = unsafe {
+            libc::read(
+                self.fd,
+                values.as_mut_ptr() as *mut libc::c_void,
+                std::mem::size_of_val(&values),
+            )
+        };
+        if n != std::mem::size_of_val(&values) as isize {
+            return Err(io::Error::last_os_error());
+        }
+
+        let [value, enabled, running] = values;
+        if running == 0 || running == enabled {
+            return Ok(value);
+        }
+        Ok((value as f64 * enabled as f64 / running as f64) as u64)
+    }
+
+    fn ioctl(&self, request: c_ulong) -> io::Result<()> {
+        if unsafe { libc::ioctl(self.fd, request as _, 0) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PerfCounter {
+    fn drop(&mut self) {
+        let _ = self.disable();
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HardwareEvent {
+    Instructions,
+    BranchMisses,
+    CacheMisses,
+}
+
+impl HardwareEvent {
+    const ALL: [HardwareEvent; 3] = [
+        HardwareEvent::Instructions,
+        HardwareEvent::BranchMisses,
+        HardwareEvent::CacheMisses,
+    ];
+
+    fn config(&self) -> u64 {
+        match self {
+            HardwareEvent::Instructions => PERF_COUNT_HW_INSTRUCTIONS,
+            HardwareEvent::BranchMisses => PERF_COUNT_HW_BRANCH_MISSES,
+            HardwareEvent::CacheMisses => PERF_COUNT_HW_CACHE_MISSES,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            HardwareEvent::Instructions => "instructions",
+            HardwareEvent::BranchMisses => "branch-misses",
+            HardwareEvent::CacheMisses => "cache-misses",
+        }
+    }
+}
+
+/// Instructions retired, branch misses dan cache misses untuk thread ini.
+/// Counter yang tidak bisa dibuka dilewati dan dicatat di `note()`.
+pub struct HardwareCounters {
+    counters: Vec<(HardwareEvent, PerfCounter)>,
+    unavailable: Vec<String>,
+}
+
+impl HardwareCounters {
+    pub fn open() -> Self {
+        let mut counters = Vec::new();
+        let mut unavailable = Vec::new();
+
+        for event in HardwareEvent::ALL {
+            match PerfCounter::open(PERF_TYPE_HARDWARE, event.config()) {
+                Ok(counter) => {
+                    let _ = counter.disable();
+                    counters.push((event, counter));
+                }
+                Err(e) => unavailable.push(format!("{} ({})", event.name(), describe_open_error(&e))),
+            }
+        }
+
+        HardwareCounters { counters, unavailable }
+    }
+
+    /// Reset dan aktifkan semua counter, tepat sebelum sample group
+    pub fn start(&self) {
+        for (_, counter) in &self.counters {
+            let _ = counter.reset();
+            let _ = counter.enable();
+        }
+    }
+
+    /// Hentikan counter dan bagi total dengan jumlah operasi yang diukur
+    pub fn stop(&self, operations: u64) -> Option<HardwareCounterStats> {
+        for (_, counter) in &self.counters {
+            let _ = counter.disable();
+        }
+        if self.counters.is_empty() || operations == 0 {
+            return None;
+        }
+
+        let mut stats = HardwareCounterStats::default();
+        for (event, counter) in &self.counters {
+            let Ok(total) = counter.read() else { continue };
+            let per_op = Some(total as f64 / operations as f64);
+            match event {
+                HardwareEvent::Instructions => stats.instructions_per_op = per_op,
+                HardwareEvent::BranchMisses => stats.branch_misses_per_op = per_op,
+                HardwareEvent::CacheMisses => stats.cache_misses_per_op = per_op,
+            }
+        }
+        Some(stats)
+    }
+
+    /// Catatan untuk `extra_info` jika ada counter yang tidak tersedia
+    pub fn note(&self) -> Option<String> {
+        if self.unavailable.is_empty() {
+            return None;
+        }
+        Some(format!("unavailable: {}", self.unavailable.join(", ")))
+    }
+}
+
+fn describe_open_error(e: &io::Error) -> String {
+    match e.raw_os_error() {
+        Some(libc::EACCES) | Some(libc::EPERM) => {
+            match std::fs::read_to_string("/proc/sys/kernel/perf_event_paranoid") {
+                Ok(level) => format!("perf_event_paranoid={}", level.trim()),
+                Err(_) => "permission denied".to_string(),
+            }
+        }
+        Some(libc::ENOENT) | Some(libc::EOPNOTSUPP) => "not supported by this CPU/VM".to_string(),
+        Some(libc::ENOSYS) => "perf_event_open not available".to_string(),
+        _ => e.to_string(),
+    }
+}
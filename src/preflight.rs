@@ -0,0 +1,250 @@
+// Pre-flight check sebelum pengukuran
+//
+// Opsional: pin thread benchmark ke satu core, baca CPU frequency governor dan
+// status turbo dari sysfs, lalu cek kondisi yang jelas-jelas noisy (load
+// average tinggi, proses lain yang sibuk). Temuan dicatat di report; dengan
+// `Strictness::Strict` pemanggil sebaiknya menolak menjalankan benchmark.
+//
+// Pin hanya berlaku selama `CpuPin` hidup: thread yang dibuat sesudahnya
+// mewarisi mask thread pembuatnya, jadi mask semula harus dikembalikan setelah
+// bagian yang diukur (dan thread bantu memakai `unpin_helper_thread`).
+use std::time::Duration;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+use crate::constants::{PREFLIGHT_BUSY_PROCESS_CPU_PERCENT, PREFLIGHT_MAX_LOAD_PER_CPU};
+use crate::models::PreflightReport;
+
+/// Apa yang dilakukan jika pre-flight menemukan masalah
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Strictness {
+    /// Catat dan tampilkan warning, tetap jalankan benchmark
+    #[default]
+    Warn,
+    /// Tolak menjalankan benchmark jika ada warning
+    Strict,
+}
+
+impl Strictness {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "warn" => Some(Strictness::Warn),
+            "strict" => Some(Strictness::Strict),
+            _ => None,
+        }
+    }
+}
+
+/// CPU affinity thread pemanggil sebelum di-pin. Saat di-drop, mask semula
+/// dikembalikan; tanpa pin, drop tidak melakukan apa-apa.
+#[must_use = "dropping the pin immediately restores the original CPU affinity"]
+pub struct CpuPin {
+    #[cfg(target_os = "linux")]
+    original: Option<libc::cpu_set_t>,
+}
+
+impl CpuPin {
+    fn none() -> Self {
+        CpuPin {
+            #[cfg(target_os = "linux")]
+            original: None,
+        }
+    }
+}
+
+impl Drop for CpuPin {
+    fn drop(&mut self) {
+        #[cfg(target_os = "linux")]
+        if let Some(original) = self.original.take() {
+            set_affinity(&original);
+            *UNPINNED_MASK.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        }
+    }
+}
+
+/// Mask sebelum pin, selama `CpuPin` masih hidup
+#[cfg(target_os = "linux")]
+static UNPINNED_MASK: std::sync::Mutex<Option<libc::cpu_set_t>> = std::sync::Mutex::new(None);
+
+/// Kembalikan thread bantu (mis. sampler RSS) ke mask sebelum pin, supaya tidak
+/// berebut core dengan thread yang diukur
+pub fn unpin_helper_thread() {
+    #[cfg(target_os = "linux")]
+    if let Some(mask) = *UNPINNED_MASK.lock().unwrap_or_else(|e| e.into_inner()) {
+        set_affinity(&mask);
+    }
+}
+
+/// Jalankan pre-flight untuk thread pemanggil. Jika `pin_core` diisi, thread
+/// ini di-pin ke core tersebut sampai `CpuPin` di-drop, dan governor/turbo
+/// dibaca untuk core itu.
+pub fn run(pin_core: Option<usize>) -> (PreflightReport, CpuPin) {
+    let mut warnings = Vec::new();
+
+    let mut pin = CpuPin::none();
+    let pinned_core = pin_core.and_then(|core| match pin_current_thread(core) {
+        Ok(pinned) => {
+            pin = pinned;
+            Some(core)
+        }
+        Err(e) => {
+            warnings.push(format!("could not pin to CPU {}: {}", core, e));
+            None
+        }
+    });
+
+    let governor_core = pinned_core.unwrap_or(0);
+    let governor = read_governor(governor_core);
+    if let Some(governor) = governor.as_deref().filter(|g| *g != "performance") {
+        warnings.push(format!(
+            "CPU {} frequency governor is '{}' (use 'performance' for stable numbers)",
+            governor_core, governor
+        ));
+    }
+
+    let turbo_enabled = read_turbo_enabled();
+    if turbo_enabled == Some(true) {
+        warnings.push("turbo/boost is enabled; clock speed will vary with temperature".to_string());
+    }
+
+    let logical_cpus = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let load_average_1m = read_load_average();
+    if let Some(load) = load_average_1m.filter(|load| *load > PREFLIGHT_MAX_LOAD_PER_CPU * logical_cpus as f64) {
+        warnings.push(format!("high load average {:.2} on {} logical CPUs", load, logical_cpus));
+    }
+
+    let busy_processes = busy_processes();
+    if !busy_processes.is_empty() {
+        warnings.push(format!("other busy processes: {}", busy_processes.join(", ")));
+    }
+
+    let report = PreflightReport {
+        pinned_core,
+        governor,
+        turbo_enabled,
+        load_average_1m,
+        logical_cpus,
+        busy_processes,
+        warnings,
+    };
+    (report, pin)
+}
+
+#[cfg(target_os = "linux")]
+fn set_affinity(set: &libc::cpu_set_t) -> bool {
+    // pid 0 = thread pemanggil
+    unsafe { libc::sched_setaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), set) == 0 }
+}
+
+#[cfg(target_os = "linux")]
+fn pin_current_thread(core: usize) -> std::io::Result<CpuPin> {
+    if core >= libc::CPU_SETSIZE as usize {
+        return Err(std::io::Error::from(std::io::ErrorKind::InvalidInput));
+    }
+    let (original, set) = unsafe {
+        let mut original: libc::cpu_set_t = std::mem::zeroed();
+        if libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut original) != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        let mut set: libc::cpu_set_t = std::mem::zeroed();
+        libc::CPU_SET(core, &mut set);
+        (original, set)
+    };
+    if !set_affinity(&set) {
+        return Err(std::io::Error::last_os_error());
+    }
+    *UNPINNED_MASK.lock().unwrap_or_else(|e| e.into_inner()) = Some(original);
+    Ok(CpuPin { original: Some(original) })
+}
+
+#[cfg(not(target_os = "linux"))]
+fn pin_current_thread(_core: usize) -> std::io::Result<CpuPin> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "CPU pinning is only supported on Linux",
+    ))
+}
+
+fn read_sysfs(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok().map(|s| s.trim().to_string())
+}
+
+pub(crate) fn read_governor(core: usize) -> Option<String> {
+    read_sysfs(&format!("/sys/devices/system/cpu/cpu{}/cpufreq/scaling_governor", core))
+}
+
+/// intel_pstate memakai `no_turbo` (terbalik), acpi-cpufreq/amd memakai `boost`
+pub(crate) fn read_turbo_enabled() -> Option<bool> {
+    if let Some(no_turbo) = read_sysfs("/sys/devices/system/cpu/intel_pstate/no_turbo") {
+        return Some(no_turbo == "0");
+    }
+    read_sysfs("/sys/devices/system/cpu/cpufreq/boost").map(|boost| boost == "1")
+}
+
+fn read_load_average() -> Option<f64> {
+    // Windows tidak punya load average (sysinfo mengembalikan 0)
+    if cfg!(windows) {
+        return None;
+    }
+    Some(System::load_average().one)
+}
+
+/// Proses lain dengan CPU usage di atas threshold, diukur selama satu interval
+fn busy_processes() -> Vec<String> {
+    let own_pid = sysinfo::get_current_pid().ok();
+    let mut sys = System::new();
+    let refresh = |sys: &mut System| {
+        sys.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing().with_cpu());
+    };
+
+    refresh(&mut sys);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL.max(Duration::from_millis(200)));
+    refresh(&mut sys);
+
+    let mut busy: Vec<(f32, String)> = sys
+        .processes()
+        .iter()
+        .filter(|(pid, _)| Some(**pid) != own_pid)
+        .filter(|(_, process)| process.cpu_usage() > PREFLIGHT_BUSY_PROCESS_CPU_PERCENT)
+        .map(|(pid, process)| {
+            let name = process.name().to_string_lossy();
+            (process.cpu_usage(), format!("{} (pid {}, {:.0}% CPU)", name, pid, process.cpu_usage()))
+        })
+        .collect();
+    busy.sort_by(|a, b| b.0.total_cmp(&a.0));
+    busy.into_iter().map(|(_, description)| description).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strictness_parse() {
+        assert_eq!(Strictness::parse("warn"), Some(Strictness::Warn));
+        assert_eq!(Strictness::parse("strict"), Some(Strictness::Strict));
+        assert_eq!(Strictness::parse("loud"), None);
+    }
+
+    #[test]
+    fn test_invalid_core_is_reported_not_fatal() {
+        let (report, _pin) = run(Some(usize::MAX));
+        assert_eq!(report.pinned_core, None);
+        assert!(report.warnings.iter().any(|w| w.contains("could not pin")));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_pin_is_undone_on_drop() {
+        let affinity = || unsafe {
+            let mut set: libc::cpu_set_t = std::mem::zeroed();
+            libc::sched_getaffinity(0, std::mem::size_of::<libc::cpu_set_t>(), &mut set);
+            libc::CPU_COUNT(&set)
+        };
+        let before = affinity();
+        let (report, pin) = run(Some(0));
+        if report.pinned_core == Some(0) {
+            assert_eq!(affinity(), 1);
+        }
+        drop(pin);
+        assert_eq!(affinity(), before);
+    }
+}
@@ -0,0 +1,168 @@
+// Resource usage proses ini (CPU time + RSS) selama benchmark satu algoritma
+//
+// Berbeda dengan `measure_resources` lama yang membaca CPU/memory global,
+// sampler ini hanya melihat proses benchmark sendiri.
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use crate::constants::SYSTEM_REFRESH_INTERVAL_MS;
+use crate::measurement::MeasurementConfig;
+use crate::models::{BenchmarkMetric, ResourceUsage};
+
+#[derive(Debug, Clone, Copy)]
+struct Snapshot {
+    cpu_user: Option<Duration>,
+    cpu_system: Option<Duration>,
+    cpu_total: Duration,
+    rss_bytes: u64,
+}
+
+fn refresh_process(sys: &mut System, pid: Pid) {
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        false,
+        ProcessRefreshKind::nothing().with_cpu().with_memory(),
+    );
+}
+
+fn take_snapshot(sys: &mut System, pid: Pid) -> Snapshot {
+    refresh_process(sys, pid);
+    let (rss_bytes, accumulated_ms) = sys
+        .process(pid)
+        .map(|p| (p.memory(), p.accumulated_cpu_time()))
+        .unwrap_or((0, 0));
+
+    // getrusage memberi pemisahan user/sys dengan resolusi μs
+    match cpu_times() {
+        Some((user, system)) => Snapshot {
+            cpu_user: Some(user),
+            cpu_system: Some(system),
+            cpu_total: user + system,
+            rss_bytes,
+        },
+        None => Snapshot {
+            cpu_user: None,
+            cpu_system: None,
+            cpu_total: Duration::from_millis(accumulated_ms),
+            rss_bytes,
+        },
+    }
+}
+
+#[cfg(unix)]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    if unsafe { libc::getrusage(libc::RUSAGE_SELF, &mut usage) } != 0 {
+        return None;
+    }
+    let to_duration = |tv: libc::timeval| {
+        Duration::from_secs(tv.tv_sec as u64) + Duration::from_micros(tv.tv_usec as u64)
+    };
+    Some((to_duration(usage.ru_utime), to_duration(usage.ru_stime)))
+}
+
+#[cfg(not(unix))]
+fn cpu_times() -> Option<(Duration, Duration)> {
+    None
+}
+
+/// Sampler aktif untuk satu benchmark window. Thread latar belakang membaca
+/// RSS setiap `SYSTEM_REFRESH_INTERVAL_MS` untuk menangkap puncaknya.
+/// Tanpa thread itu, puncak RSS hanya diambil dari awal dan akhir window.
+pub struct ResourceSampler {
+    sys: System,
+    pid: Pid,
+    start: Snapshot,
+    started_at: Instant,
+    peak_rss: Arc<AtomicU64>,
+    stop: Arc<AtomicBool>,
+    poller: Option<JoinHandle<()>>,
+}
+
+impl ResourceSampler {
+    /// `None` jika PID proses ini tidak bisa ditentukan
+    pub fn start(poll_rss: bool) -> Option<Self> {
+        let pid = sysinfo::get_current_pid().ok()?;
+        let mut sys = System::new();
+        let start = take_snapshot(&mut sys, pid);
+
+        let peak_rss = Arc::new(AtomicU64::new(start.rss_bytes));
+        let stop = Arc::new(AtomicBool::new(false));
+        let poller = if poll_rss {
+            let peak_rss = Arc::clone(&peak_rss);
+            let stop = Arc::clone(&stop);
+            std::thread::Builder::new()
+                .name("rss-sampler".to_string())
+                .spawn(move || {
+                    crate::preflight::unpin_helper_thread();
+                    let mut sys = System::new();
+                    while !stop.load(Ordering::Relaxed) {
+                        std::thread::park_timeout(Duration::from_millis(SYSTEM_REFRESH_INTERVAL_MS));
+                        refresh_process(&mut sys, pid);
+                        if let Some(process) = sys.process(pid) {
+                            peak_rss.fetch_max(process.memory(), Ordering::Relaxed);
+                        }
+                    }
+                })
+                .ok()
+        } else {
+            None
+        };
+
+        Some(ResourceSampler {
+            sys,
+            pid,
+            start,
+            started_at: Instant::now(),
+            peak_rss,
+            stop,
+            poller,
+        })
+    }
+
+    /// Tutup window dan hitung selisih CPU time serta RSS
+    pub fn finish(mut self, algorithm: &str) -> ResourceUsage {
+        let wall = self.started_at.elapsed();
+        let end = take_snapshot(&mut self.sys, self.pid);
+
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(poller) = self.poller.take() {
+            poller.thread().unpark();
+            let _ = poller.join();
+        }
+
+        let delta = |end: Option<Duration>, start: Option<Duration>| {
+            Some(end?.saturating_sub(start?).as_secs_f64())
+        };
+        let cpu_total = end.cpu_total.saturating_sub(self.start.cpu_total).as_secs_f64();
+
+        ResourceUsage {
+            algorithm: algorithm.to_string(),
+            wall_time_secs: wall.as_secs_f64(),
+            cpu_user_secs: delta(end.cpu_user, self.start.cpu_user),
+            cpu_system_secs: delta(end.cpu_system, self.start.cpu_system),
+            cpu_total_secs: cpu_total,
+            cpu_utilization_percent: if wall.is_zero() { 0.0 } else { cpu_total / wall.as_secs_f64() * 100.0 },
+            rss_start_bytes: self.start.rss_bytes,
+            rss_end_bytes: end.rss_bytes,
+            rss_peak_bytes: self.peak_rss.load(Ordering::Relaxed).max(end.rss_bytes),
+        }
+    }
+}
+
+/// Jalankan benchmark satu algoritma dalam resource window-nya sendiri.
+/// Nama algoritma diambil dari metric pertama yang dihasilkan. Dengan
+/// `track_allocations` RSS tidak di-poll: refresh sysinfo di thread lain ikut
+/// terhitung oleh counter alokasi yang global (lihat `alloc`).
+pub fn sample_algorithm<F>(config: &MeasurementConfig, f: F) -> (Vec<BenchmarkMetric>, Option<ResourceUsage>)
+where
+    F: FnOnce() -> Vec<BenchmarkMetric>,
+{
+    let sampler = ResourceSampler::start(!config.track_allocations);
+    let metrics = f();
+    let algorithm = metrics.first().map(|m| m.name.as_str()).unwrap_or_default();
+    let usage = sampler.map(|s| s.finish(algorithm));
+    (metrics, usage)
+}
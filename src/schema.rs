@@ -0,0 +1,186 @@
+// Schema versi JSON report: loader yang meng-upgrade versi lama dan JSON Schema
+//
+// Report selalu ditulis dengan `REPORT_SCHEMA_VERSION`. Saat dibaca, JSON
+// di-parse dulu sebagai `serde_json::Value`, di-upgrade satu versi per langkah
+// sampai versi sekarang, baru di-deserialize ke `BenchmarkReport`. Dokumen
+// JSON Schema ada di `schema/benchmark-report.schema.json` (dibuat dari tipe
+// di models.rs, lihat test di bawah).
+use std::fmt;
+use serde_json::{Map, Value};
+use crate::constants::REPORT_SCHEMA_VERSION;
+use crate::models::BenchmarkReport;
+
+/// Report tanpa `schema_version` berasal dari sebelum field itu ada
+const UNVERSIONED_SCHEMA_VERSION: u32 = 1;
+
+/// `extra_info` versi 1 → field di `sizes` versi 2
+const V1_SIZE_KEYS: [(&str, &str); 7] = [
+    ("key_size", "key"),
+    ("public_key_size", "public_key"),
+    ("secret_key_size", "secret_key"),
+    ("signature_size", "signature"),
+    ("ciphertext_size", "ciphertext"),
+    ("shared_secret_size", "shared_secret"),
+    ("encoded_size", "encoded"),
+];
+
+#[derive(Debug)]
+pub enum SchemaError {
+    Json(serde_json::Error),
+    /// Report dari versi tool yang lebih baru
+    UnsupportedVersion(u32),
+    InvalidVersion(Value),
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SchemaError::Json(e) => write!(f, "{}", e),
+            SchemaError::UnsupportedVersion(version) => write!(
+                f,
+                "report schema version {} is newer than the supported version {}",
+                version, REPORT_SCHEMA_VERSION
+            ),
+            SchemaError::InvalidVersion(value) => write!(f, "invalid schema_version {}", value),
+        }
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+impl From<serde_json::Error> for SchemaError {
+    fn from(e: serde_json::Error) -> Self {
+        SchemaError::Json(e)
+    }
+}
+
+/// Parse report versi apa pun yang didukung
+pub fn load_report(json: &str) -> Result<BenchmarkReport, SchemaError> {
+    let value = upgrade(serde_json::from_str(json)?)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Upgrade JSON report ke `REPORT_SCHEMA_VERSION` tanpa deserialize
+pub fn upgrade(mut report: Value) -> Result<Value, SchemaError> {
+    let mut version = match report.get("schema_version") {
+        None => UNVERSIONED_SCHEMA_VERSION,
+        Some(value) => value
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .ok_or_else(|| SchemaError::InvalidVersion(value.clone()))?,
+    };
+    if version > REPORT_SCHEMA_VERSION {
+        return Err(SchemaError::UnsupportedVersion(version));
+    }
+
+    while version < REPORT_SCHEMA_VERSION {
+        if version == 1 {
+            upgrade_v1_metrics(&mut report);
+        }
+        version += 1;
+    }
+    if let Some(object) = report.as_object_mut() {
+        object.insert("schema_version".to_string(), Value::from(REPORT_SCHEMA_VERSION));
+    }
+    Ok(report)
+}
+
+/// v1 → v2: string "32 bytes"/"2048 bits" di `extra_info` pindah ke `sizes`.
+/// Metric dikenali dari `mean_micros` + `extra_info`, di section mana pun
+/// (termasuk yang bersarang seperti `cache_modes[].warm`).
+fn upgrade_v1_metrics(value: &mut Value) {
+    match value {
+        Value::Array(items) => items.iter_mut().for_each(upgrade_v1_metrics),
+        Value::Object(object) => {
+            if object.contains_key("mean_micros") {
+                if let Some(Value::Object(extra_info)) = object.get_mut("extra_info") {
+                    let sizes = take_v1_sizes(extra_info);
+                    object.entry("sizes").or_insert(Value::Object(sizes));
+                }
+            }
+            object.values_mut().for_each(upgrade_v1_metrics);
+        }
+        _ => {}
+    }
+}
+
+fn take_v1_sizes(extra_info: &mut Map<String, Value>) -> Map<String, Value> {
+    let mut sizes = Map::new();
+    for (old_key, new_key) in V1_SIZE_KEYS {
+        let parsed = extra_info.get(old_key).and_then(Value::as_str).and_then(parse_v1_size);
+        if let Some((value, unit)) = parsed {
+            extra_info.remove(old_key);
+            sizes.insert(new_key.to_string(), serde_json::json!({ "value": value, "unit": unit }));
+        }
+    }
+    sizes
+}
+
+/// "32 bytes" → (32, "bytes"); string lain dibiarkan di `extra_info`
+fn parse_v1_size(text: &str) -> Option<(u64, &'static str)> {
+    let (number, unit) = text.trim().split_once(' ')?;
+    let unit = match unit.trim() {
+        "bytes" | "byte" | "B" => "bytes",
+        "bits" | "bit" => "bits",
+        _ => return None,
+    };
+    Some((number.parse().ok()?, unit))
+}
+
+/// JSON Schema (draft 2020-12) untuk report versi sekarang
+pub fn json_schema() -> Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(BenchmarkReport))
+        .expect("JSON Schema is always serializable");
+    if let Some(object) = schema.as_object_mut() {
+        object.insert(
+            "description".to_string(),
+            Value::from(format!("crypto_benchmark report, schema_version {}", REPORT_SCHEMA_VERSION)),
+        );
+    }
+    schema
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Size, SizeUnit};
+
+    #[test]
+    fn test_v1_report_is_upgraded() {
+        let v1 = r#"{
+            "signatures": [{
+                "name": "RSA-2048", "operation": "Sign",
+                "mean_micros": 900.0, "min_micros": 850.0, "max_micros": 1200.0,
+                "std_dev_micros": 40.0, "iterations": 100,
+                "extra_info": {"key_size": "2048 bits", "signature_size": "256 bytes", "note": "kept"}
+            }],
+            "kem": [],
+            "keygen_time_secs": 0.5,
+            "total_time_secs": 2.0
+        }"#;
+        let report = load_report(v1).unwrap();
+        assert_eq!(report.schema_version.0, REPORT_SCHEMA_VERSION);
+
+        let metric = &report.signatures[0];
+        assert_eq!(metric.sizes.key, Some(Size { value: 2048, unit: SizeUnit::Bits }));
+        assert_eq!(metric.sizes.signature, Some(Size::bytes(256)));
+        assert_eq!(metric.extra_info.len(), 1);
+        assert_eq!(metric.extra_info.get("note").map(String::as_str), Some("kept"));
+    }
+
+    #[test]
+    fn test_newer_version_is_rejected() {
+        let future = format!(r#"{{"schema_version": {}}}"#, REPORT_SCHEMA_VERSION + 1);
+        assert!(matches!(load_report(&future), Err(SchemaError::UnsupportedVersion(_))));
+    }
+
+    /// Dokumen yang dipublikasikan harus sama dengan yang dihasilkan dari tipe;
+    /// jalankan `cargo run -- schema > schema/benchmark-report.schema.json`
+    /// setelah mengubah models.rs.
+    #[test]
+    fn test_published_schema_is_up_to_date() {
+        let published: Value =
+            serde_json::from_str(include_str!("../schema/benchmark-report.schema.json")).unwrap();
+        assert_eq!(published, json_schema());
+    }
+}
@@ -0,0 +1,437 @@
+// Encode/decode benchmark untuk key, signature dan ciphertext
+//
+// Server mem-parse public key dan signature dari wire di setiap request.
+// Benchmark utama hanya mengukur operasi pada tipe yang sudah di-parse di
+// `BenchmarkKeys`; modul ini mengukur biaya parsing dan serialisasinya.
+// Setiap format dilaporkan sebagai operasi tersendiri.
+use std::collections::HashMap;
+use ed25519_dalek::Signer;
+use rsa::pkcs1::{DecodeRsaPrivateKey, DecodeRsaPublicKey, EncodeRsaPrivateKey, EncodeRsaPublicKey};
+use rsa::pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey, EncodePublicKey, LineEnding};
+use rsa::signature::SignatureEncoding;
+use rsa::traits::PublicKeyParts;
+use rsa::{Pss, RsaPrivateKey, RsaPublicKey};
+use sha2::{Digest, Sha256};
+use pqcrypto_traits::kem::{Ciphertext as _, PublicKey as _, SecretKey as _};
+use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _, SecretKey as _};
+use pqcrypto_mldsa::mldsa44;
+use pqcrypto_falcon::falcon512;
+use pqcrypto_mlkem::mlkem512;
+use rand::rngs::OsRng;
+use crate::constants::DEFAULT_MESSAGE;
+use crate::keys::BenchmarkKeys;
+use crate::measurement::{benchmark_operation_with, BenchmarkResult, MeasurementConfig};
+use crate::models::{ArtifactSizes, BenchmarkMetric, Size};
+
+const BENCH_ITERATIONS: usize = 100;
+
+fn codec_metric(name: &str, operation: &str, encoded_size: usize, result: BenchmarkResult) -> BenchmarkMetric {
+    let sizes = ArtifactSizes { encoded: Some(Size::bytes(encoded_size)), ..Default::default() };
+    result.to_metric(name.to_string(), operation.to_string(), HashMap::new()).with_sizes(sizes)
+}
+
+/// Decode/encode semua key, signature dan ciphertext dari `keys`, hanya untuk
+/// algoritma yang lolos `config.algorithms`
+pub fn benchmark_serialization(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let mut metrics = Vec::new();
+    metrics.extend(benchmark_ed25519_codec(keys, config));
+    metrics.extend(benchmark_rsa_codec(keys, config));
+    metrics.extend(benchmark_ecdsa_codec(keys, config));
+    metrics.extend(benchmark_pq_signature_codec(keys, config));
+    metrics.extend(benchmark_kem_codec(keys, config));
+    metrics
+}
+
+fn benchmark_ed25519_codec(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let name = "Ed25519";
+    if !config.includes(name) {
+        return Vec::new();
+    }
+    let public_bytes = keys.ed25519_verifying.to_bytes();
+    let secret_bytes = keys.ed25519_signing.to_bytes();
+    let signature = keys.ed25519_signing.sign(DEFAULT_MESSAGE);
+    let signature_bytes = signature.to_bytes();
+
+    vec![
+        // Dekompresi point Edwards + validasi
+        codec_metric(name, "Decode Public Key", public_bytes.len(), benchmark_operation_with(
+            || ed25519_dalek::VerifyingKey::from_bytes(&public_bytes).expect("Ed25519 public key decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key", public_bytes.len(), benchmark_operation_with(
+            || keys.ed25519_verifying.to_bytes(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        // Termasuk menurunkan public key dari seed (scalar multiplication)
+        codec_metric(name, "Decode Secret Key", secret_bytes.len(), benchmark_operation_with(
+            || ed25519_dalek::SigningKey::from_bytes(&secret_bytes),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Secret Key", secret_bytes.len(), benchmark_operation_with(
+            || keys.ed25519_signing.to_bytes(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Signature", signature_bytes.len(), benchmark_operation_with(
+            || ed25519_dalek::Signature::from_slice(&signature_bytes).expect("Ed25519 signature decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Signature", signature_bytes.len(), benchmark_operation_with(
+            || signature.to_bytes(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+    ]
+}
+
+fn benchmark_rsa_codec(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let name = "RSA-2048";
+    if !config.includes(name) {
+        return Vec::new();
+    }
+    let public_pkcs1_der = keys.rsa_public.to_pkcs1_der().expect("RSA PKCS#1 encode failed");
+    let public_spki_der = keys.rsa_public.to_public_key_der().expect("RSA SPKI encode failed");
+    let public_spki_pem = keys.rsa_public.to_public_key_pem(LineEnding::LF).expect("RSA SPKI PEM encode failed");
+    let private_pkcs1_der = keys.rsa_private.to_pkcs1_der().expect("RSA PKCS#1 encode failed");
+    let private_pkcs8_der = keys.rsa_private.to_pkcs8_der().expect("RSA PKCS#8 encode failed");
+    let private_pkcs8_pem = keys.rsa_private.to_pkcs8_pem(LineEnding::LF).expect("RSA PKCS#8 PEM encode failed");
+    let signature_bytes = keys.rsa_private
+        .sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), &Sha256::digest(DEFAULT_MESSAGE))
+        .expect("RSA signing failed");
+    // PSS signature di wire adalah big-endian integer sepanjang modulus.
+    // `pss::Signature` sendiri tidak mengecek apa pun, jadi panjang dan s < n
+    // dicek sekali di sini, di luar bagian yang diukur
+    let modulus_bytes = keys.rsa_public.n().to_bytes_be();
+    assert!(
+        signature_bytes.len() == modulus_bytes.len() && signature_bytes.as_slice() < modulus_bytes.as_slice(),
+        "RSA signature out of range"
+    );
+    let signature = rsa::pss::Signature::try_from(signature_bytes.as_slice()).expect("RSA signature decode failed");
+
+    vec![
+        codec_metric(name, "Decode Public Key (PKCS#1 DER)", public_pkcs1_der.as_bytes().len(), benchmark_operation_with(
+            || RsaPublicKey::from_pkcs1_der(public_pkcs1_der.as_bytes()).expect("RSA PKCS#1 decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key (PKCS#1 DER)", public_pkcs1_der.as_bytes().len(), benchmark_operation_with(
+            || keys.rsa_public.to_pkcs1_der().expect("RSA PKCS#1 encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Public Key (SPKI DER)", public_spki_der.as_bytes().len(), benchmark_operation_with(
+            || RsaPublicKey::from_public_key_der(public_spki_der.as_bytes()).expect("RSA SPKI decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key (SPKI DER)", public_spki_der.as_bytes().len(), benchmark_operation_with(
+            || keys.rsa_public.to_public_key_der().expect("RSA SPKI encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Public Key (SPKI PEM)", public_spki_pem.len(), benchmark_operation_with(
+            || RsaPublicKey::from_public_key_pem(&public_spki_pem).expect("RSA SPKI PEM decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key (SPKI PEM)", public_spki_pem.len(), benchmark_operation_with(
+            || keys.rsa_public.to_public_key_pem(LineEnding::LF).expect("RSA SPKI PEM encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        // Private key decode memvalidasi key dan menghitung ulang nilai CRT
+        codec_metric(name, "Decode Secret Key (PKCS#1 DER)", private_pkcs1_der.as_bytes().len(), benchmark_operation_with(
+            || RsaPrivateKey::from_pkcs1_der(private_pkcs1_der.as_bytes()).expect("RSA PKCS#1 decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Secret Key (PKCS#1 DER)", private_pkcs1_der.as_bytes().len(), benchmark_operation_with(
+            || keys.rsa_private.to_pkcs1_der().expect("RSA PKCS#1 encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Secret Key (PKCS#8 DER)", private_pkcs8_der.as_bytes().len(), benchmark_operation_with(
+            || RsaPrivateKey::from_pkcs8_der(private_pkcs8_der.as_bytes()).expect("RSA PKCS#8 decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Secret Key (PKCS#8 DER)", private_pkcs8_der.as_bytes().len(), benchmark_operation_with(
+            || keys.rsa_private.to_pkcs8_der().expect("RSA PKCS#8 encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Secret Key (PKCS#8 PEM)", private_pkcs8_pem.len(), benchmark_operation_with(
+            || RsaPrivateKey::from_pkcs8_pem(&private_pkcs8_pem).expect("RSA PKCS#8 PEM decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Secret Key (PKCS#8 PEM)", private_pkcs8_pem.len(), benchmark_operation_with(
+            || keys.rsa_private.to_pkcs8_pem(LineEnding::LF).expect("RSA PKCS#8 PEM encode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Signature", signature_bytes.len(), benchmark_operation_with(
+            || rsa::pss::Signature::try_from(signature_bytes.as_slice()).expect("RSA signature decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Signature", signature_bytes.len(), benchmark_operation_with(
+            || signature.to_vec(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+    ]
+}
+
+fn benchmark_ecdsa_codec(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let name = "ECDSA P-256";
+    if !config.includes(name) {
+        return Vec::new();
+    }
+    let compressed = keys.ecdsa_verifying.to_encoded_point(true);
+    let uncompressed = keys.ecdsa_verifying.to_encoded_point(false);
+    let secret_bytes = keys.ecdsa_signing.to_bytes();
+    let signature: p256::ecdsa::Signature = keys.ecdsa_signing.sign(DEFAULT_MESSAGE);
+    let signature_der = signature.to_der();
+    let signature_fixed = signature.to_bytes();
+
+    vec![
+        // SEC1 compressed butuh square root di field untuk memulihkan y
+        codec_metric(name, "Decode Public Key (SEC1 compressed)", compressed.len(), benchmark_operation_with(
+            || p256::ecdsa::VerifyingKey::from_sec1_bytes(compressed.as_bytes()).expect("SEC1 decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key (SEC1 compressed)", compressed.len(), benchmark_operation_with(
+            || keys.ecdsa_verifying.to_encoded_point(true),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Public Key (SEC1 uncompressed)", uncompressed.len(), benchmark_operation_with(
+            || p256::ecdsa::VerifyingKey::from_sec1_bytes(uncompressed.as_bytes()).expect("SEC1 decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Public Key (SEC1 uncompressed)", uncompressed.len(), benchmark_operation_with(
+            || keys.ecdsa_verifying.to_encoded_point(false),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Secret Key", secret_bytes.len(), benchmark_operation_with(
+            || p256::ecdsa::SigningKey::from_slice(&secret_bytes).expect("P-256 secret key decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Secret Key", secret_bytes.len(), benchmark_operation_with(
+            || keys.ecdsa_signing.to_bytes(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Signature (DER)", signature_der.as_bytes().len(), benchmark_operation_with(
+            || p256::ecdsa::Signature::from_der(signature_der.as_bytes()).expect("ECDSA DER decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Decode Signature (fixed)", signature_fixed.len(), benchmark_operation_with(
+            || p256::ecdsa::Signature::from_slice(&signature_fixed).expect("ECDSA signature decode failed"),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Signature (DER)", signature_der.as_bytes().len(), benchmark_operation_with(
+            || signature.to_der(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+        codec_metric(name, "Encode Signature (fixed)", signature_fixed.len(), benchmark_operation_with(
+            || signature.to_bytes(),
+            BENCH_ITERATIONS,
+            config,
+        )),
+    ]
+}
+
+fn benchmark_pq_signature_codec(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let mut metrics = Vec::new();
+
+    let name = "Dilithium (ML-DSA-44)";
+    if config.includes(name) {
+        let public_bytes = keys.dilithium_public.as_bytes().to_vec();
+        let secret_bytes = keys.dilithium_secret.as_bytes().to_vec();
+        let signature = mldsa44::detached_sign(DEFAULT_MESSAGE, &keys.dilithium_secret);
+        let signature_bytes = signature.as_bytes().to_vec();
+        metrics.extend([
+            codec_metric(name, "Decode Public Key", public_bytes.len(), benchmark_operation_with(
+                || mldsa44::PublicKey::from_bytes(&public_bytes).expect("ML-DSA public key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Public Key", public_bytes.len(), benchmark_operation_with(
+                || keys.dilithium_public.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || mldsa44::SecretKey::from_bytes(&secret_bytes).expect("ML-DSA secret key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || keys.dilithium_secret.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Signature", signature_bytes.len(), benchmark_operation_with(
+                || mldsa44::DetachedSignature::from_bytes(&signature_bytes).expect("ML-DSA signature decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Signature", signature_bytes.len(), benchmark_operation_with(
+                || signature.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+
+    let name = "Falcon-512";
+    if config.includes(name) {
+        let public_bytes = keys.falcon_public.as_bytes().to_vec();
+        let secret_bytes = keys.falcon_secret.as_bytes().to_vec();
+        let signature = falcon512::detached_sign(DEFAULT_MESSAGE, &keys.falcon_secret);
+        let signature_bytes = signature.as_bytes().to_vec();
+        metrics.extend([
+            codec_metric(name, "Decode Public Key", public_bytes.len(), benchmark_operation_with(
+                || falcon512::PublicKey::from_bytes(&public_bytes).expect("Falcon public key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Public Key", public_bytes.len(), benchmark_operation_with(
+                || keys.falcon_public.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || falcon512::SecretKey::from_bytes(&secret_bytes).expect("Falcon secret key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || keys.falcon_secret.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Signature", signature_bytes.len(), benchmark_operation_with(
+                || falcon512::DetachedSignature::from_bytes(&signature_bytes).expect("Falcon signature decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Signature", signature_bytes.len(), benchmark_operation_with(
+                || signature.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+    metrics
+}
+
+fn benchmark_kem_codec(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let mut metrics = Vec::new();
+
+    // X25519: key adalah 32 byte mentah, decode hanya copy (secret key
+    // di-clamp saat dipakai, bukan saat decode)
+    let name = "X25519 Diffie-Hellman";
+    if config.includes(name) {
+        let x25519_secret = x25519_dalek::StaticSecret::random_from_rng(OsRng);
+        let x25519_public = x25519_dalek::PublicKey::from(&x25519_secret);
+        let x25519_bytes = x25519_public.to_bytes();
+        let x25519_secret_bytes = x25519_secret.to_bytes();
+        metrics.extend([
+            codec_metric(name, "Decode Public Key", x25519_bytes.len(), benchmark_operation_with(
+                || x25519_dalek::PublicKey::from(x25519_bytes),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Public Key", x25519_bytes.len(), benchmark_operation_with(
+                || x25519_public.to_bytes(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Secret Key", x25519_secret_bytes.len(), benchmark_operation_with(
+                || x25519_dalek::StaticSecret::from(x25519_secret_bytes),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Secret Key", x25519_secret_bytes.len(), benchmark_operation_with(
+                || x25519_secret.to_bytes(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+
+    // ECDH P-256: SEC1 compressed point. Secret ECDH bersifat ephemeral dan
+    // tidak punya encoding, jadi hanya public key yang diukur
+    let name = "ECDH (P-256)";
+    if config.includes(name) {
+        let p256_public = p256::ecdh::EphemeralSecret::random(&mut OsRng).public_key();
+        let p256_bytes = p256::EncodedPoint::from(p256_public).compress();
+        metrics.extend([
+            codec_metric(name, "Decode Public Key (SEC1 compressed)", p256_bytes.len(), benchmark_operation_with(
+                || p256::PublicKey::from_sec1_bytes(p256_bytes.as_bytes()).expect("SEC1 decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Public Key (SEC1 compressed)", p256_bytes.len(), benchmark_operation_with(
+                || p256::EncodedPoint::from(p256_public).compress(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+
+    let name = "Kyber (ML-KEM-512)";
+    if config.includes(name) {
+        let public_bytes = keys.kyber_public.as_bytes().to_vec();
+        let secret_bytes = keys.kyber_secret.as_bytes().to_vec();
+        let (_, ciphertext) = mlkem512::encapsulate(&keys.kyber_public);
+        let ciphertext_bytes = ciphertext.as_bytes().to_vec();
+        metrics.extend([
+            codec_metric(name, "Decode Public Key", public_bytes.len(), benchmark_operation_with(
+                || mlkem512::PublicKey::from_bytes(&public_bytes).expect("ML-KEM public key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Public Key", public_bytes.len(), benchmark_operation_with(
+                || keys.kyber_public.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || mlkem512::SecretKey::from_bytes(&secret_bytes).expect("ML-KEM secret key decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Secret Key", secret_bytes.len(), benchmark_operation_with(
+                || keys.kyber_secret.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Decode Ciphertext", ciphertext_bytes.len(), benchmark_operation_with(
+                || mlkem512::Ciphertext::from_bytes(&ciphertext_bytes).expect("ML-KEM ciphertext decode failed"),
+                BENCH_ITERATIONS,
+                config,
+            )),
+            codec_metric(name, "Encode Ciphertext", ciphertext_bytes.len(), benchmark_operation_with(
+                || ciphertext.as_bytes().to_vec(),
+                BENCH_ITERATIONS,
+                config,
+            )),
+        ]);
+    }
+    metrics
+}
@@ -7,9 +7,9 @@ use std::net::SocketAddr;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tower_http::services::ServeDir;
-use crypto_benchmark::BenchmarkKeys;
-use crypto_benchmark::models::BenchmarkReport;
-use crypto_benchmark::{signatures, kem};
+use crypto_benchmark::{BenchmarkKeys, MeasurementConfig};
+use crypto_benchmark::models::{BenchmarkReport, ReferenceAlgorithms};
+use crypto_benchmark::{environment, normalize, signatures, kem};
 use std::time::Instant;
 
 // State shared across requests
@@ -19,9 +19,10 @@ struct AppState {
     // For now, we'll run them every time or maybe cache them?
     // Let's cache them to avoid DDOSing the CPU
     cached_report: Mutex<Option<BenchmarkReport>>,
+    include_hostname: bool,
 }
 
-pub async fn start_server(port: u16) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn start_server(port: u16, include_hostname: bool) -> Result<(), Box<dyn std::error::Error>> {
     println!("⏳ Generating keys for server...");
     let keys = BenchmarkKeys::generate()?;
     println!("✓ Keys generated. Starting server...");
@@ -29,6 +30,7 @@ pub async fn start_server(port: u16) -> Result<(), Box<dyn std::error::Error>> {
     let state = Arc::new(AppState {
         keys: Arc::new(keys),
         cached_report: Mutex::new(None),
+        include_hostname,
     });
 
     let app = Router::new()
@@ -61,21 +63,28 @@ async fn run_benchmarks(State(state): State<Arc<AppState>>) -> Json<BenchmarkRep
     // Since benchmarking is CPU intensive, we should probably run it in a blocking task
     // to avoid blocking the async runtime.
     let keys_clone = state.keys.clone();
+    let include_hostname = state.include_hostname;
 
     let report = tokio::task::spawn_blocking(move || {
-        let sig_metrics = signatures::benchmark_signatures_optimized(&keys_clone);
-        let kem_metrics = kem::benchmark_kem_optimized(&keys_clone);
+        let config = MeasurementConfig::default();
+        let environment = environment::capture(&config, include_hostname);
+        let (sig_metrics, sig_resources) = signatures::benchmark_signatures_with_resources(&keys_clone, &config);
+        let (kem_metrics, kem_resources) = kem::benchmark_kem_with_resources(&keys_clone, &config);
 
         BenchmarkReport {
             signatures: sig_metrics,
             kem: kem_metrics,
             keygen_time_secs: 0.0, // Cached
             total_time_secs: 0.0, // Will be calculated
+            resources: sig_resources.into_iter().chain(kem_resources).collect(),
+            environment: Some(environment),
+            ..Default::default()
         }
     }).await.unwrap();
 
     let mut report = report;
     report.total_time_secs = start_bench.elapsed().as_secs_f64();
+    normalize::normalize(&mut report, &ReferenceAlgorithms::default());
 
     // Update cache
     let mut cache = state.cached_report.lock().await;
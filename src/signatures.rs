@@ -1,8 +1,10 @@
 // Optimized signatures module dengan pre-generated keys
-use crate::keys::BenchmarkKeys;
-use crate::measurement::benchmark_operation;
-use crate::constants::DEFAULT_MESSAGE;
-use crate::models::BenchmarkMetric;
+use crate::keys::{BenchmarkKeys, KeyPool, KeySet};
+use crate::measurement::{benchmark_operation_with, BenchmarkResult, MeasurementConfig};
+use crate::constants::{DEFAULT_MESSAGE, RSA_KEY_SIZE};
+use crate::models::{ArtifactSizes, BatchVerifyResult, BenchmarkMetric, KeyPoolComparison, ResourceUsage, Size};
+use crate::implementations::IMPL_PQCRYPTO;
+use crate::resources::sample_algorithm;
 use std::collections::HashMap;
 use ed25519_dalek::Signer;
 use rsa::Pss;
@@ -13,199 +15,568 @@ use pqcrypto_falcon::falcon512;
 const BENCH_ITERATIONS: usize = 100;
 
 pub fn benchmark_signatures_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
-    let mut metrics = Vec::new();
-    
-    // Ed25519
-    metrics.extend(benchmark_ed25519_optimized(keys));
-
-    // RSA
-    metrics.extend(benchmark_rsa_optimized(keys));
-
-    // ECDSA
-    metrics.extend(benchmark_ecdsa_optimized(keys));
+    benchmark_signatures_with_config(keys, &MeasurementConfig::default())
+}
 
-    // Dilithium (ML-DSA)
-    metrics.extend(benchmark_dilithium_optimized(keys));
+pub fn benchmark_signatures_with_config(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    benchmark_signatures_with_resources(keys, config).0
+}
 
-    // Falcon
-    metrics.extend(benchmark_falcon_optimized(keys));
+/// Jalankan semua signature benchmark dan catat CPU/RSS proses per algoritma
+pub fn benchmark_signatures_with_resources(
+    keys: &BenchmarkKeys,
+    config: &MeasurementConfig,
+) -> (Vec<BenchmarkMetric>, Vec<ResourceUsage>) {
+    let runs: [(&str, &dyn Fn() -> Vec<BenchmarkMetric>); 5] = [
+        ("Ed25519", &|| benchmark_ed25519_optimized(keys, config)),
+        ("RSA-2048", &|| benchmark_rsa_optimized(keys, config)),
+        ("ECDSA P-256", &|| benchmark_ecdsa_optimized(keys, config)),
+        ("Dilithium (ML-DSA-44)", &|| benchmark_dilithium_optimized(keys, config)),
+        ("Falcon-512", &|| benchmark_falcon_optimized(keys, config)),
+    ];
 
-    metrics
+    let mut metrics = Vec::new();
+    let mut resources = Vec::new();
+    for (name, run) in runs {
+        if !config.includes(name) {
+            continue;
+        }
+        let (algorithm_metrics, usage) = sample_algorithm(config, run);
+        metrics.extend(algorithm_metrics);
+        resources.extend(usage);
+    }
+    (metrics, resources)
 }
 
-fn benchmark_ed25519_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_ed25519_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     let message = DEFAULT_MESSAGE;
     
     // Benchmark signing
-    let sign_result = benchmark_operation(
-        || keys.ed25519_signing.sign(message),
-        BENCH_ITERATIONS
+    let sign_result = benchmark_operation_with(
+        || sign_ed25519(keys, message),
+        BENCH_ITERATIONS,
+        config,
     );
     
     // Pre-generate signature untuk verification benchmark
-    let signature = keys.ed25519_signing.sign(message);
+    let signature = sign_ed25519(keys, message);
     
     // Benchmark verification
-    let verify_result = benchmark_operation(
-        || {
-            use ed25519_dalek::Verifier;
-            keys.ed25519_verifying.verify(message, &signature).expect("Verification failed")
-        },
-        BENCH_ITERATIONS
+    let verify_result = benchmark_operation_with(
+        || verify_ed25519(keys, message, &signature),
+        BENCH_ITERATIONS,
+        config,
     );
     
-    let mut info = HashMap::new();
-    info.insert("key_size".to_string(), "32 bytes".to_string());
-    info.insert("signature_size".to_string(), format!("{} bytes", signature.to_bytes().len()));
+    let sizes = ArtifactSizes {
+        key: Some(Size::bytes(32)),
+        signature: Some(Size::bytes(signature.to_bytes().len())),
+        ..Default::default()
+    };
 
     vec![
-        sign_result.to_metric("Ed25519".to_string(), "Sign".to_string(), info.clone()),
-        verify_result.to_metric("Ed25519".to_string(), "Verify".to_string(), info)
+        sign_result.to_metric("Ed25519".to_string(), "Sign".to_string(), HashMap::new()).with_implementation("ed25519-dalek").with_sizes(sizes.clone()),
+        verify_result.to_metric("Ed25519".to_string(), "Verify".to_string(), HashMap::new()).with_implementation("ed25519-dalek").with_sizes(sizes)
     ]
 }
 
-fn benchmark_rsa_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_rsa_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     let message = DEFAULT_MESSAGE;
     
     // Pre-compute hash
-    let mut hasher = Sha256::new();
-    hasher.update(message);
-    let hashed = hasher.finalize();
+    let hashed = Sha256::digest(message);
     
     // Benchmark signing
-    let sign_result = benchmark_operation(
-        || {
-            let padding = Pss::new::<Sha256>();
-            keys.rsa_private.sign_with_rng(&mut rand::rngs::OsRng, padding, &hashed)
-                .expect("RSA signing failed")
-        },
-        50  // RSA is slower, fewer iterations
+    let sign_result = benchmark_operation_with(
+        || sign_rsa(keys, &hashed),
+        50,  // RSA is slower, fewer iterations
+        config,
     );
     
     // Pre-generate signature
-    let padding = Pss::new::<Sha256>();
-    let signature = keys.rsa_private.sign_with_rng(&mut rand::rngs::OsRng, padding, &hashed)
-        .expect("RSA signing failed");
+    let signature = sign_rsa(keys, &hashed);
     
     // Benchmark verification
-    let verify_result = benchmark_operation(
-        || {
-            let padding = Pss::new::<Sha256>();
-            keys.rsa_public.verify(padding, &hashed, &signature)
-                .expect("RSA verification failed")
-        },
-        BENCH_ITERATIONS
+    let verify_result = benchmark_operation_with(
+        || verify_rsa(keys, &hashed, &signature),
+        BENCH_ITERATIONS,
+        config,
     );
     
-    let mut info = HashMap::new();
-    info.insert("key_size".to_string(), "2048 bits".to_string());
-    info.insert("signature_size".to_string(), format!("{} bytes", signature.len()));
+    let sizes = ArtifactSizes {
+        key: Some(Size::bits(RSA_KEY_SIZE)),
+        signature: Some(Size::bytes(signature.len())),
+        ..Default::default()
+    };
 
     vec![
-        sign_result.to_metric("RSA-2048".to_string(), "Sign".to_string(), info.clone()),
-        verify_result.to_metric("RSA-2048".to_string(), "Verify".to_string(), info)
+        sign_result.to_metric("RSA-2048".to_string(), "Sign".to_string(), HashMap::new()).with_implementation("rsa").with_sizes(sizes.clone()),
+        verify_result.to_metric("RSA-2048".to_string(), "Verify".to_string(), HashMap::new()).with_implementation("rsa").with_sizes(sizes)
     ]
 }
 
-fn benchmark_ecdsa_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_ecdsa_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     let message = DEFAULT_MESSAGE;
     
     // Benchmark signing
-    let sign_result = benchmark_operation(
-        || {
-            let signature: p256::ecdsa::Signature = keys.ecdsa_signing.sign(message);
-            signature
-        },
-        BENCH_ITERATIONS
+    let sign_result = benchmark_operation_with(
+        || sign_ecdsa(keys, message),
+        BENCH_ITERATIONS,
+        config,
     );
     
     // Pre-generate signature
-    let signature: p256::ecdsa::Signature = keys.ecdsa_signing.sign(message);
+    let signature = sign_ecdsa(keys, message);
     
     // Benchmark verification
-    let verify_result = benchmark_operation(
-        || {
-            use p256::ecdsa::signature::Verifier;
-            keys.ecdsa_verifying.verify(message, &signature)
-                .expect("ECDSA verification failed")
-        },
-        BENCH_ITERATIONS
+    let verify_result = benchmark_operation_with(
+        || verify_ecdsa(keys, message, &signature),
+        BENCH_ITERATIONS,
+        config,
     );
     
-    let mut info = HashMap::new();
-    info.insert("key_size".to_string(), "32 bytes".to_string());
-    info.insert("signature_size".to_string(), format!("{} bytes", signature.to_vec().len()));
+    let sizes = ArtifactSizes {
+        key: Some(Size::bytes(32)),
+        signature: Some(Size::bytes(signature.to_vec().len())),
+        ..Default::default()
+    };
 
     vec![
-        sign_result.to_metric("ECDSA P-256".to_string(), "Sign".to_string(), info.clone()),
-        verify_result.to_metric("ECDSA P-256".to_string(), "Verify".to_string(), info)
+        sign_result.to_metric("ECDSA P-256".to_string(), "Sign".to_string(), HashMap::new()).with_implementation("p256").with_sizes(sizes.clone()),
+        verify_result.to_metric("ECDSA P-256".to_string(), "Verify".to_string(), HashMap::new()).with_implementation("p256").with_sizes(sizes)
     ]
 }
 
-fn benchmark_dilithium_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_dilithium_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     let message = DEFAULT_MESSAGE;
     
     // Benchmark signing
-    let sign_result = benchmark_operation(
-        || mldsa44::sign(message, &keys.dilithium_secret),
-        50  // PQC is slower
+    let sign_result = benchmark_operation_with(
+        || sign_dilithium(keys, message),
+        50,  // PQC is slower
+        config,
     );
     
     // Pre-generate signature
-    let signature = mldsa44::sign(message, &keys.dilithium_secret);
+    let signature = sign_dilithium(keys, message);
     
     // Benchmark verification
-    let verify_result = benchmark_operation(
-        || {
-            mldsa44::open(&signature, &keys.dilithium_public)
-                .expect("Dilithium verification failed")
-        },
-        BENCH_ITERATIONS
+    let verify_result = benchmark_operation_with(
+        || verify_dilithium(keys, &signature),
+        BENCH_ITERATIONS,
+        config,
     );
     
-    let mut info = HashMap::new();
-    info.insert("public_key_size".to_string(), format!("{} bytes", mldsa44::public_key_bytes()));
-    info.insert("secret_key_size".to_string(), format!("{} bytes", mldsa44::secret_key_bytes()));
-    info.insert("signature_size".to_string(), format!("{} bytes", mldsa44::signature_bytes()));
+    let sizes = ArtifactSizes {
+        public_key: Some(Size::bytes(mldsa44::public_key_bytes())),
+        secret_key: Some(Size::bytes(mldsa44::secret_key_bytes())),
+        signature: Some(Size::bytes(mldsa44::signature_bytes())),
+        ..Default::default()
+    };
 
     vec![
-        sign_result.to_metric("Dilithium (ML-DSA-44)".to_string(), "Sign".to_string(), info.clone()),
-        verify_result.to_metric("Dilithium (ML-DSA-44)".to_string(), "Verify".to_string(), info)
+        sign_result.to_metric("Dilithium (ML-DSA-44)".to_string(), "Sign".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes.clone()),
+        verify_result.to_metric("Dilithium (ML-DSA-44)".to_string(), "Verify".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes)
     ]
 }
 
-fn benchmark_falcon_optimized(keys: &BenchmarkKeys) -> Vec<BenchmarkMetric> {
+fn benchmark_falcon_optimized(keys: &BenchmarkKeys, config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
     let message = DEFAULT_MESSAGE;
     
     // Benchmark signing
-    let sign_result = benchmark_operation(
-        || falcon512::sign(message, &keys.falcon_secret),
-        50  // PQC is slower
+    let sign_result = benchmark_operation_with(
+        || sign_falcon(keys, message),
+        50,  // PQC is slower
+        config,
     );
     
     // Pre-generate signature
-    let signature = falcon512::sign(message, &keys.falcon_secret);
+    let signature = sign_falcon(keys, message);
     
     // Benchmark verification
-    let verify_result = benchmark_operation(
-        || {
-            falcon512::open(&signature, &keys.falcon_public)
-                .expect("Falcon verification failed")
-        },
-        BENCH_ITERATIONS
+    let verify_result = benchmark_operation_with(
+        || verify_falcon(keys, &signature),
+        BENCH_ITERATIONS,
+        config,
     );
     
-    let mut info = HashMap::new();
-    info.insert("public_key_size".to_string(), format!("{} bytes", falcon512::public_key_bytes()));
-    info.insert("secret_key_size".to_string(), format!("{} bytes", falcon512::secret_key_bytes()));
-    info.insert("signature_size".to_string(), format!("{} bytes", falcon512::signature_bytes()));
+    let sizes = ArtifactSizes {
+        public_key: Some(Size::bytes(falcon512::public_key_bytes())),
+        secret_key: Some(Size::bytes(falcon512::secret_key_bytes())),
+        signature: Some(Size::bytes(falcon512::signature_bytes())),
+        ..Default::default()
+    };
 
     vec![
-        sign_result.to_metric("Falcon-512".to_string(), "Sign".to_string(), info.clone()),
-        verify_result.to_metric("Falcon-512".to_string(), "Verify".to_string(), info)
+        sign_result.to_metric("Falcon-512".to_string(), "Sign".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes.clone()),
+        verify_result.to_metric("Falcon-512".to_string(), "Verify".to_string(), HashMap::new()).with_implementation(IMPL_PQCRYPTO).with_sizes(sizes)
     ]
 }
 
+// Operasi Sign/Verify per algoritma, dipakai suite utama dan `throughput`
+// supaya keduanya mengukur panggilan yang sama. Hash RSA dan signature untuk
+// Verify disiapkan pemanggil di luar bagian yang diukur.
+
+pub(crate) fn sign_ed25519(keys: &BenchmarkKeys, message: &[u8]) -> Ed25519Signature {
+    keys.ed25519_signing.sign(message)
+}
+
+pub(crate) fn verify_ed25519(keys: &BenchmarkKeys, message: &[u8], signature: &Ed25519Signature) {
+    use ed25519_dalek::Verifier;
+    keys.ed25519_verifying.verify(message, signature).expect("Verification failed")
+}
+
+/// `hashed` adalah SHA-256 dari pesan
+pub(crate) fn sign_rsa(keys: &BenchmarkKeys, hashed: &[u8]) -> Vec<u8> {
+    keys.rsa_private
+        .sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), hashed)
+        .expect("RSA signing failed")
+}
+
+pub(crate) fn verify_rsa(keys: &BenchmarkKeys, hashed: &[u8], signature: &[u8]) {
+    keys.rsa_public.verify(Pss::new::<Sha256>(), hashed, signature).expect("RSA verification failed")
+}
+
+pub(crate) fn sign_ecdsa(keys: &BenchmarkKeys, message: &[u8]) -> p256::ecdsa::Signature {
+    keys.ecdsa_signing.sign(message)
+}
+
+pub(crate) fn verify_ecdsa(keys: &BenchmarkKeys, message: &[u8], signature: &p256::ecdsa::Signature) {
+    use p256::ecdsa::signature::Verifier;
+    keys.ecdsa_verifying.verify(message, signature).expect("ECDSA verification failed")
+}
+
+pub(crate) fn sign_dilithium(keys: &BenchmarkKeys, message: &[u8]) -> mldsa44::SignedMessage {
+    mldsa44::sign(message, &keys.dilithium_secret)
+}
+
+pub(crate) fn verify_dilithium(keys: &BenchmarkKeys, signed: &mldsa44::SignedMessage) -> Vec<u8> {
+    mldsa44::open(signed, &keys.dilithium_public).expect("Dilithium verification failed")
+}
+
+pub(crate) fn sign_falcon(keys: &BenchmarkKeys, message: &[u8]) -> falcon512::SignedMessage {
+    falcon512::sign(message, &keys.falcon_secret)
+}
+
+pub(crate) fn verify_falcon(keys: &BenchmarkKeys, signed: &falcon512::SignedMessage) -> Vec<u8> {
+    falcon512::open(signed, &keys.falcon_public).expect("Falcon verification failed")
+}
+
+/// Jumlah iterasi sweep: pesan besar memakai lebih sedikit sample
+fn sweep_iterations(message_size: usize) -> usize {
+    (BENCH_ITERATIONS * 64 * 1024 / message_size.max(1)).clamp(10, BENCH_ITERATIONS)
+}
+
+fn sweep_metric(name: &str, operation: &str, message_size: usize, result: BenchmarkResult) -> BenchmarkMetric {
+    let mut metric = result.to_metric(name.to_string(), operation.to_string(), HashMap::new());
+    metric.message_size = Some(message_size);
+    if metric.mean_micros > 0.0 {
+        metric.bytes_per_sec = Some(message_size as f64 / (metric.mean_micros / 1e6));
+    }
+    metric
+}
+
+/// Sign/verify latency dan bytes/sec untuk berbagai ukuran pesan.
+///
+/// Berbeda dengan benchmark utama, hashing pesan ikut diukur (RSA) dan
+/// ML-DSA/Falcon memakai detached signature supaya `sign`/`open` tidak
+/// menyalin seluruh pesan ke dalam signed message. Algoritma yang tidak lolos
+/// `config.algorithms` dilewati sebelum diukur.
+pub fn benchmark_message_sweep(keys: &BenchmarkKeys, message_sizes: &[usize], config: &MeasurementConfig) -> Vec<BenchmarkMetric> {
+    let mut metrics = Vec::new();
+
+    for &size in message_sizes {
+        let mut message = vec![0u8; size];
+        OsRng.fill_bytes(&mut message);
+        let message = message.as_slice();
+        let iterations = sweep_iterations(size);
+
+        // Ed25519 (hash seluruh pesan dua kali dengan SHA-512)
+        if config.includes("Ed25519") {
+            let signature = keys.ed25519_signing.sign(message);
+            metrics.push(sweep_metric("Ed25519", "Sign", size, benchmark_operation_with(
+                || keys.ed25519_signing.sign(message),
+                iterations,
+                config,
+            )));
+            metrics.push(sweep_metric("Ed25519", "Verify", size, benchmark_operation_with(
+                || {
+                    use ed25519_dalek::Verifier;
+                    keys.ed25519_verifying.verify(message, &signature).expect("Verification failed")
+                },
+                iterations,
+                config,
+            )));
+        }
+
+        // RSA (hash-then-sign, SHA-256 termasuk dalam pengukuran)
+        if config.includes("RSA-2048") {
+            let signature = keys.rsa_private
+                .sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), &Sha256::digest(message))
+                .expect("RSA signing failed");
+            metrics.push(sweep_metric("RSA-2048", "Sign", size, benchmark_operation_with(
+                || {
+                    keys.rsa_private.sign_with_rng(&mut OsRng, Pss::new::<Sha256>(), &Sha256::digest(message))
+                        .expect("RSA signing failed")
+                },
+                iterations,
+                config,
+            )));
+            metrics.push(sweep_metric("RSA-2048", "Verify", size, benchmark_operation_with(
+                || {
+                    keys.rsa_public.verify(Pss::new::<Sha256>(), &Sha256::digest(message), &signature)
+                        .expect("RSA verification failed")
+                },
+                iterations,
+                config,
+            )));
+        }
+
+        // ECDSA (SHA-256 internal)
+        if config.includes("ECDSA P-256") {
+            let signature: p256::ecdsa::Signature = keys.ecdsa_signing.sign(message);
+            metrics.push(sweep_metric("ECDSA P-256", "Sign", size, benchmark_operation_with(
+                || -> p256::ecdsa::Signature { keys.ecdsa_signing.sign(message) },
+                iterations,
+                config,
+            )));
+            metrics.push(sweep_metric("ECDSA P-256", "Verify", size, benchmark_operation_with(
+                || {
+                    use p256::ecdsa::signature::Verifier;
+                    keys.ecdsa_verifying.verify(message, &signature).expect("ECDSA verification failed")
+                },
+                iterations,
+                config,
+            )));
+        }
+
+        // Dilithium (ML-DSA), hashing internal dengan SHAKE256
+        if config.includes("Dilithium (ML-DSA-44)") {
+            let signature = mldsa44::detached_sign(message, &keys.dilithium_secret);
+            metrics.push(sweep_metric("Dilithium (ML-DSA-44)", "Sign", size, benchmark_operation_with(
+                || mldsa44::detached_sign(message, &keys.dilithium_secret),
+                iterations,
+                config,
+            )));
+            metrics.push(sweep_metric("Dilithium (ML-DSA-44)", "Verify", size, benchmark_operation_with(
+                || {
+                    mldsa44::verify_detached_signature(&signature, message, &keys.dilithium_public)
+                        .expect("Dilithium verification failed")
+                },
+                iterations,
+                config,
+            )));
+        }
+
+        // Falcon
+        if config.includes("Falcon-512") {
+            let signature = falcon512::detached_sign(message, &keys.falcon_secret);
+            metrics.push(sweep_metric("Falcon-512", "Sign", size, benchmark_operation_with(
+                || falcon512::detached_sign(message, &keys.falcon_secret),
+                iterations,
+                config,
+            )));
+            metrics.push(sweep_metric("Falcon-512", "Verify", size, benchmark_operation_with(
+                || {
+                    falcon512::verify_detached_signature(&signature, message, &keys.falcon_public)
+                        .expect("Falcon verification failed")
+                },
+                iterations,
+                config,
+            )));
+        }
+    }
+
+    metrics
+}
+
+/// Verify dengan satu key (set pertama) vs berputar di seluruh pool, dengan
+/// closure `verify`, iterasi dan config yang sama. Keduanya batched dan warm;
+/// bedanya hanya apakah key/signature berganti. Verify suite utama tidak
+/// dipakai sebagai pembanding karena operasinya bisa berbeda (RSA memakai
+/// hash yang sudah dihitung, ML-DSA/Falcon memakai `open`).
+fn pool_comparison<F, R>(name: &str, pool: &KeyPool, verify: F, config: &MeasurementConfig) -> Option<KeyPoolComparison>
+where
+    F: Fn(&KeySet) -> R + Sync,
+{
+    if !config.includes(name) {
+        return None;
+    }
+    let single_set = &pool.sets[0];
+    let single_key = benchmark_operation_with(|| verify(single_set), BENCH_ITERATIONS, config)
+        .to_metric(name.to_string(), "Verify".to_string(), HashMap::new());
+
+    let mut next = 0;
+    let mut key_pool = benchmark_operation_with(
+        || {
+            let set = &pool.sets[next % pool.len()];
+            next += 1;
+            verify(set)
+        },
+        BENCH_ITERATIONS,
+        config,
+    )
+    .to_metric(name.to_string(), "Verify (key pool)".to_string(), HashMap::new());
+    key_pool.extra_info.insert("pool_size".to_string(), pool.len().to_string());
+
+    Some(KeyPoolComparison {
+        name: name.to_string(),
+        pool_size: pool.len(),
+        difference_percent: if single_key.mean_micros > 0.0 {
+            (key_pool.mean_micros / single_key.mean_micros - 1.0) * 100.0
+        } else {
+            0.0
+        },
+        single_key,
+        key_pool,
+    })
+}
+
+/// Verifikasi dengan banyak keypair dan signature berbeda. Menampakkan biaya
+/// per-key yang tersembunyi saat satu key dipakai berulang, mis. ekspansi
+/// matrix public key ML-DSA dan cache miss pada key/signature yang berganti.
+/// Key Ed25519/ECDSA/RSA di `KeySet` sudah di-parse, jadi decoding (mis.
+/// dekompresi point Ed25519) tidak ikut terukur; lihat `serialization`.
+pub fn benchmark_verify_key_pool(pool: &KeyPool, config: &MeasurementConfig) -> Vec<KeyPoolComparison> {
+    use ed25519_dalek::Verifier;
+
+    [
+        pool_comparison(
+            "Ed25519",
+            pool,
+            |s| s.ed25519_verifying.verify(&s.message, &s.ed25519_signature).expect("Verification failed"),
+            config,
+        ),
+        pool_comparison(
+            "RSA-2048",
+            pool,
+            |s| {
+                s.rsa_public.verify(Pss::new::<Sha256>(), &Sha256::digest(&s.message), &s.rsa_signature)
+                    .expect("RSA verification failed")
+            },
+            config,
+        ),
+        pool_comparison(
+            "ECDSA P-256",
+            pool,
+            |s| s.ecdsa_verifying.verify(&s.message, &s.ecdsa_signature).expect("ECDSA verification failed"),
+            config,
+        ),
+        pool_comparison(
+            "Dilithium (ML-DSA-44)",
+            pool,
+            |s| {
+                mldsa44::verify_detached_signature(&s.dilithium_signature, &s.message, &s.dilithium_public)
+                    .expect("Dilithium verification failed")
+            },
+            config,
+        ),
+        pool_comparison(
+            "Falcon-512",
+            pool,
+            |s| {
+                falcon512::verify_detached_signature(&s.falcon_signature, &s.message, &s.falcon_public)
+                    .expect("Falcon verification failed")
+            },
+            config,
+        ),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Batch Ed25519 signature, masing-masing dengan key dan pesan sendiri
+pub struct Ed25519Batch {
+    pub messages: Vec<Vec<u8>>,
+    pub signatures: Vec<Ed25519Signature>,
+    pub verifying_keys: Vec<ed25519_dalek::VerifyingKey>,
+}
+
+impl Ed25519Batch {
+    pub fn generate(size: usize) -> Self {
+        let mut batch = Ed25519Batch {
+            messages: Vec::with_capacity(size),
+            signatures: Vec::with_capacity(size),
+            verifying_keys: Vec::with_capacity(size),
+        };
+        for _ in 0..size {
+            let mut secret_bytes = [0u8; 32];
+            OsRng.fill_bytes(&mut secret_bytes);
+            let signing_key = ed25519_dalek::SigningKey::from_bytes(&secret_bytes);
+            let mut message = vec![0u8; DEFAULT_MESSAGE.len()];
+            OsRng.fill_bytes(&mut message);
+
+            batch.signatures.push(signing_key.sign(&message));
+            batch.verifying_keys.push(signing_key.verifying_key());
+            batch.messages.push(message);
+        }
+        batch
+    }
+
+    pub fn len(&self) -> usize {
+        self.signatures.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.signatures.is_empty()
+    }
+
+    /// Slice pesan untuk `verify`. Dibuat sekali di luar bagian yang diukur,
+    /// supaya alokasi `Vec` tidak ikut masuk biaya per signature.
+    pub fn message_slices(&self) -> Vec<&[u8]> {
+        self.messages.iter().map(Vec::as_slice).collect()
+    }
+
+    /// Gagal jika satu saja signature di batch tidak valid.
+    /// `messages` harus berasal dari `message_slices` batch ini.
+    pub fn verify(&self, messages: &[&[u8]]) -> Result<(), ed25519_dalek::SignatureError> {
+        ed25519_dalek::verify_batch(messages, &self.signatures, &self.verifying_keys)
+    }
+}
+
+/// Ed25519 batch verification untuk setiap ukuran batch, dibandingkan dengan
+/// verifikasi satu per satu. Biaya amortized = waktu batch / jumlah signature.
+pub fn benchmark_ed25519_batch_verify(batch_sizes: &[usize], config: &MeasurementConfig) -> Vec<BatchVerifyResult> {
+    use ed25519_dalek::Verifier;
+
+    let single = Ed25519Batch::generate(1);
+    let single_verify = benchmark_operation_with(
+        || {
+            single.verifying_keys[0].verify(&single.messages[0], &single.signatures[0])
+                .expect("Verification failed")
+        },
+        BENCH_ITERATIONS,
+        config,
+    );
+    let single_verify_micros = single_verify.mean_duration.as_secs_f64() * 1e6;
+
+    batch_sizes
+        .iter()
+        .filter(|&&size| size > 0)
+        .map(|&size| {
+            let batch = Ed25519Batch::generate(size);
+            let messages = batch.message_slices();
+            let mut metric = benchmark_operation_with(
+                || batch.verify(&messages).expect("Batch verification failed"),
+                (BENCH_ITERATIONS * 16 / size).clamp(10, BENCH_ITERATIONS),
+                config,
+            )
+            .to_metric("Ed25519".to_string(), format!("Batch Verify ({})", size), HashMap::new());
+            metric.extra_info.insert("batch_size".to_string(), size.to_string());
+
+            let per_signature_micros = metric.mean_micros / size as f64;
+            BatchVerifyResult {
+                batch_size: size,
+                per_signature_micros,
+                single_verify_micros,
+                speedup: if per_signature_micros > 0.0 { single_verify_micros / per_signature_micros } else { 0.0 },
+                batch: metric,
+            }
+        })
+        .collect()
+}
+
 // Helper functions for Criterion benchmarks
 use ed25519_dalek::Signature as Ed25519Signature;
 use p256::ecdsa::SigningKey as P256SigningKey;
@@ -306,6 +677,16 @@ mod tests {
     use super::*;
     use crate::BenchmarkKeys;
 
+    #[test]
+    fn test_ed25519_batch_rejects_one_bad_signature() {
+        let mut batch = Ed25519Batch::generate(16);
+        assert!(batch.verify(&batch.message_slices()).is_ok());
+
+        // Signature ke-7 dipasangkan dengan pesan lain
+        batch.signatures[7] = batch.signatures[8];
+        assert!(batch.verify(&batch.message_slices()).is_err());
+    }
+
     #[test]
     fn test_ed25519_sign_and_verify() {
         let keys = BenchmarkKeys::generate().unwrap();
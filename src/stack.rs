@@ -0,0 +1,112 @@
+// Stack high-water mark per operasi
+//
+// Operasi dijalankan di thread khusus. Sebelum pemanggilan, area stack di
+// bawah frame saat ini di-"paint" dengan pola yang diketahui; setelah
+// pemanggilan, area tersebut di-scan dari bawah untuk menemukan byte terdalam
+// yang ditimpa. Asumsi: stack tumbuh ke bawah (semua target yang didukung).
+// Jika seluruh area tertimpa, pemakaian sebenarnya tidak diketahui (hanya
+// batas bawah) dan hasilnya ditandai `saturated`.
+use std::hint::black_box;
+use std::mem::MaybeUninit;
+use crate::constants::{STACK_PAINT_BYTES, STACK_THREAD_SIZE};
+
+const PAINT_PATTERN: u8 = 0xA5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackUsage {
+    pub bytes: usize,
+    /// Seluruh `STACK_PAINT_BYTES` tertimpa: `bytes` hanya batas bawah
+    pub saturated: bool,
+}
+
+/// Peak stack usage satu pemanggilan `f`.
+/// `None` jika thread pengukur gagal dibuat.
+pub fn measure_stack_usage<F, R>(mut f: F) -> Option<StackUsage>
+where
+    F: FnMut() -> R + Send,
+{
+    std::thread::scope(|scope| {
+        std::thread::Builder::new()
+            .name("stack-probe".to_string())
+            .stack_size(STACK_THREAD_SIZE)
+            .spawn_scoped(scope, move || {
+                // Warmup: inisialisasi lazy (OnceLock, CPU feature detection)
+                // jangan sampai ikut terukur
+                black_box(f());
+
+                let (bottom, top) = paint_stack();
+                black_box(f());
+                Some(scan_stack(bottom, top))
+            })
+            .ok()?
+            .join()
+            .ok()?
+    })
+}
+
+/// Isi `STACK_PAINT_BYTES` di bawah frame pemanggil dengan `PAINT_PATTERN`.
+/// Mengembalikan rentang alamat [bottom, top) yang sudah di-paint.
+#[inline(never)]
+fn paint_stack() -> (usize, usize) {
+    let mut region = [MaybeUninit::<u8>::uninit(); STACK_PAINT_BYTES];
+    for byte in region.iter_mut() {
+        unsafe { std::ptr::write_volatile(byte.as_mut_ptr(), PAINT_PATTERN) };
+    }
+    let bottom = region.as_ptr() as usize;
+    black_box(&region);
+    (bottom, bottom + STACK_PAINT_BYTES)
+}
+
+/// Cari byte terdalam yang tidak lagi berisi pola, lalu hitung jaraknya ke `top`
+#[inline(never)]
+fn scan_stack(bottom: usize, top: usize) -> StackUsage {
+    let mut addr = bottom;
+    while addr < top {
+        // Area ini adalah stack thread ini yang sudah tidak dipakai frame mana pun
+        let byte = unsafe { std::ptr::read_volatile(addr as *const u8) };
+        if byte != PAINT_PATTERN {
+            break;
+        }
+        addr += 1;
+    }
+    StackUsage { bytes: top - addr, saturated: addr == bottom }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[inline(never)]
+    fn use_stack(bytes: usize) -> u8 {
+        let mut buffer = [0u8; 64 * 1024];
+        let used = &mut buffer[..bytes];
+        for (i, b) in used.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        black_box(&buffer).iter().fold(0u8, |acc, b| acc.wrapping_add(*b))
+    }
+
+    #[test]
+    fn test_stack_usage_covers_local_buffer() {
+        let used = measure_stack_usage(|| use_stack(64 * 1024)).expect("stack probe failed");
+        assert!(used.bytes >= 64 * 1024, "measured only {} bytes", used.bytes);
+        assert!(used.bytes < STACK_PAINT_BYTES);
+        assert!(!used.saturated);
+    }
+
+    #[inline(never)]
+    fn use_large_stack() -> u8 {
+        let mut buffer = [0u8; 2 * STACK_PAINT_BYTES];
+        for (i, b) in buffer.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        black_box(&buffer).iter().fold(0u8, |acc, b| acc.wrapping_add(*b))
+    }
+
+    #[test]
+    fn test_stack_usage_beyond_paint_area_is_saturated() {
+        let used = measure_stack_usage(use_large_stack).expect("stack probe failed");
+        assert!(used.saturated);
+        assert_eq!(used.bytes, STACK_PAINT_BYTES);
+    }
+}
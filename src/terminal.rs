@@ -0,0 +1,184 @@
+// Output terminal: tabel rata kolom, bar chart Unicode dan warna ANSI
+//
+// Warna hanya dipakai jika stdout adalah terminal (`IsTerminal`), kecuali
+// dimatikan dengan `NO_COLOR` atau dipaksa dengan `CLICOLOR_FORCE`
+// (https://no-color.org). Lebar kolom dihitung per karakter, bukan per byte,
+// supaya "μs" dan block character tetap rata.
+use std::io::IsTerminal;
+
+/// Lebar default bar chart, dalam karakter
+pub const BAR_WIDTH: usize = 24;
+
+/// Block character 1/8 sampai 8/8
+const BLOCKS: [char; 8] = ['▏', '▎', '▍', '▌', '▋', '▊', '▉', '█'];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red,
+    Green,
+    Cyan,
+    Dim,
+    Bold,
+}
+
+impl Color {
+    fn code(&self) -> &'static str {
+        match self {
+            Color::Red => "31",
+            Color::Green => "32",
+            Color::Cyan => "36",
+            Color::Dim => "2",
+            Color::Bold => "1",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Style {
+    pub color: bool,
+}
+
+impl Style {
+    /// Deteksi otomatis untuk stdout
+    pub fn detect() -> Self {
+        let env_set = |name: &str| std::env::var_os(name).is_some_and(|v| !v.is_empty());
+        let color = if env_set("NO_COLOR") {
+            false
+        } else if std::env::var_os("CLICOLOR_FORCE").is_some_and(|v| v != "0") {
+            true
+        } else {
+            std::io::stdout().is_terminal() && std::env::var("TERM").map_or(true, |term| term != "dumb")
+        };
+        Style { color }
+    }
+
+    pub fn plain() -> Self {
+        Style { color: false }
+    }
+
+    pub fn paint(&self, text: &str, color: Color) -> String {
+        if self.color {
+            format!("\x1b[{}m{}\x1b[0m", color.code(), text)
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Bar sepanjang `value / max × width` karakter, resolusi 1/8 karakter.
+/// Nilai > 0 selalu mendapat minimal satu blok tipis.
+pub fn bar(value: f64, max: f64, width: usize) -> String {
+    if !(value > 0.0 && max > 0.0) {
+        return String::new();
+    }
+    let eighths = ((value / max).min(1.0) * (width * 8) as f64).round().max(1.0) as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if let Some(partial) = (eighths % 8).checked_sub(1) {
+        bar.push(BLOCKS[partial]);
+    }
+    bar
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+/// Tabel teks dengan kolom rata. Sel boleh berisi escape ANSI; lebar dihitung
+/// dari teks yang terlihat.
+#[derive(Debug, Clone)]
+pub struct Table {
+    headers: Vec<String>,
+    align: Vec<Align>,
+    rows: Vec<Vec<String>>,
+}
+
+fn visible_width(text: &str) -> usize {
+    let mut width = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match (in_escape, c) {
+            (false, '\x1b') => in_escape = true,
+            (true, 'm') => in_escape = false,
+            (true, _) => {}
+            (false, _) => width += 1,
+        }
+    }
+    width
+}
+
+impl Table {
+    pub fn new(columns: &[(&str, Align)]) -> Self {
+        Table {
+            headers: columns.iter().map(|(header, _)| header.to_string()).collect(),
+            align: columns.iter().map(|(_, align)| *align).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    /// Satu baris per row, diawali `indent`; header ditebalkan jika warna aktif
+    pub fn render(&self, indent: &str, style: Style) -> String {
+        let mut widths: Vec<usize> = self.headers.iter().map(|h| visible_width(h)).collect();
+        for row in &self.rows {
+            for (i, cell) in row.iter().enumerate().take(widths.len()) {
+                widths[i] = widths[i].max(visible_width(cell));
+            }
+        }
+
+        let line = |cells: &[String]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&widths)
+                .zip(&self.align)
+                .map(|((cell, width), align)| {
+                    let padding = " ".repeat(width.saturating_sub(visible_width(cell)));
+                    match align {
+                        Align::Left => format!("{}{}", cell, padding),
+                        Align::Right => format!("{}{}", padding, cell),
+                    }
+                })
+                .collect();
+            format!("{}{}\n", indent, padded.join("  ").trim_end())
+        };
+
+        let header: Vec<String> = self.headers.iter().map(|h| style.paint(h, Color::Bold)).collect();
+        let mut out = line(&header);
+        let rule: Vec<String> = widths.iter().map(|w| "─".repeat(*w)).collect();
+        out.push_str(&line(&rule));
+        for row in &self.rows {
+            out.push_str(&line(row));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_resolution() {
+        assert_eq!(bar(10.0, 10.0, 3), "███");
+        assert_eq!(bar(5.0, 10.0, 3), "█▌");
+        assert_eq!(bar(0.001, 10.0, 3), "▏");
+        assert_eq!(bar(0.0, 10.0, 3), "");
+    }
+
+    #[test]
+    fn test_table_alignment_ignores_ansi() {
+        let mut table = Table::new(&[("Name", Align::Left), ("Mean (μs)", Align::Right)]);
+        table.row(vec!["Ed25519".to_string(), "20.50".to_string()]);
+        table.row(vec![Style { color: true }.paint("RSA", Color::Red), "1000.00".to_string()]);
+        let rendered = table.render("", Style::plain());
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(lines[0], "Name     Mean (μs)");
+        assert_eq!(lines[1], "───────  ─────────");
+        assert_eq!(lines[2], "Ed25519      20.50");
+        assert_eq!(lines[3], "\x1b[31mRSA\x1b[0m        1000.00");
+    }
+}
@@ -0,0 +1,300 @@
+// Multi-threaded throughput dan scaling benchmark
+//
+// Setiap operasi dijalankan di 1..N thread selama durasi tetap, memakai
+// `BenchmarkKeys` yang sama (shared). Scaling efficiency = throughput N thread
+// dibagi (N × throughput 1 thread); nilai jauh di bawah 1.0 menandakan
+// contention atau shared state di backend.
+use std::hint::black_box;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Barrier;
+use std::time::{Duration, Instant};
+use p256::ecdh::EphemeralSecret as P256EphemeralSecret;
+use rand::rngs::OsRng;
+use sha2::{Digest, Sha256};
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey};
+use crate::constants::DEFAULT_MESSAGE;
+use crate::keys::BenchmarkKeys;
+use crate::{kem, signatures};
+use crate::measurement::MeasurementConfig;
+use crate::models::ThroughputResult;
+
+/// Jumlah thread yang diuji: 1, 2, 4, ... sampai `max_threads` (selalu termasuk)
+pub fn thread_counts(max_threads: usize) -> Vec<usize> {
+    let max_threads = max_threads.max(1);
+    let mut counts: Vec<usize> = std::iter::successors(Some(1usize), |n| n.checked_mul(2))
+        .take_while(|&n| n < max_threads)
+        .collect();
+    counts.push(max_threads);
+    counts
+}
+
+/// Jalankan `f` terus-menerus di `threads` thread selama `duration`.
+/// Mengembalikan total operasi yang selesai dan waktu aktual window-nya.
+pub fn measure_throughput<F, R>(f: &F, threads: usize, duration: Duration) -> (u64, Duration)
+where
+    F: Fn() -> R + Sync,
+{
+    let stop = AtomicBool::new(false);
+    let barrier = Barrier::new(threads + 1);
+
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|_| {
+                scope.spawn(|| {
+                    // Worker tidak boleh mewarisi pin `--pin-core` ke satu core
+                    crate::preflight::unpin_helper_thread();
+                    let mut ops = 0u64;
+                    barrier.wait();
+                    while !stop.load(Ordering::Relaxed) {
+                        black_box(f());
+                        ops += 1;
+                    }
+                    ops
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        let start = Instant::now();
+        std::thread::sleep(duration);
+        stop.store(true, Ordering::Relaxed);
+
+        let total: u64 = workers.into_iter().map(|w| w.join().unwrap_or(0)).sum();
+        (total, start.elapsed())
+    })
+}
+
+/// Ukur satu operasi untuk setiap jumlah thread dari `thread_counts`,
+/// masing-masing `windows` kali `duration` (ops/sec dari total semua window)
+pub fn scale_operation<F, R>(
+    name: &str,
+    operation: &str,
+    f: F,
+    max_threads: usize,
+    duration: Duration,
+    windows: usize,
+) -> Vec<ThroughputResult>
+where
+    F: Fn() -> R + Sync,
+{
+    // Warmup singkat supaya inisialisasi lazy tidak masuk ke 1-thread baseline
+    for _ in 0..10 {
+        black_box(f());
+    }
+
+    let mut results: Vec<ThroughputResult> = Vec::new();
+    for threads in thread_counts(max_threads) {
+        let (total_ops, elapsed) = (0..windows.max(1))
+            .map(|_| measure_throughput(&f, threads, duration))
+            .fold((0, Duration::ZERO), |(ops, time), (o, t)| (ops + o, time + t));
+        let ops_per_sec = total_ops as f64 / elapsed.as_secs_f64();
+        let single_thread = results.first().map_or(ops_per_sec, |r| r.ops_per_sec);
+
+        results.push(ThroughputResult {
+            name: name.to_string(),
+            operation: operation.to_string(),
+            threads,
+            duration_secs: elapsed.as_secs_f64(),
+            total_ops,
+            ops_per_sec,
+            ops_per_sec_per_thread: ops_per_sec / threads as f64,
+            scaling_efficiency: if single_thread > 0.0 {
+                ops_per_sec / (threads as f64 * single_thread)
+            } else {
+                0.0
+            },
+        });
+    }
+    results
+}
+
+/// Throughput semua signature dan KEM/key exchange operation dengan shared
+/// keys, hanya untuk algoritma yang lolos `config.algorithms`. Closure-nya
+/// sama dengan suite utama (`signatures`/`kem`), jadi latency dan throughput
+/// mengukur operasi yang sama. `config.iterations` = jumlah window per
+/// (operasi, jumlah thread) [default: 1].
+pub fn benchmark_throughput(
+    keys: &BenchmarkKeys,
+    max_threads: usize,
+    duration: Duration,
+    config: &MeasurementConfig,
+) -> Vec<ThroughputResult> {
+    let message = DEFAULT_MESSAGE;
+    let windows = config.iterations.unwrap_or(1).max(1);
+    let mut results = Vec::new();
+
+    if config.includes("Ed25519") {
+        let signature = signatures::sign_ed25519(keys, message);
+        results.extend(scale_operation(
+            "Ed25519",
+            "Sign",
+            || signatures::sign_ed25519(keys, message),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "Ed25519",
+            "Verify",
+            || signatures::verify_ed25519(keys, message, &signature),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("RSA-2048") {
+        let hashed = Sha256::digest(message);
+        let signature = signatures::sign_rsa(keys, &hashed);
+        results.extend(scale_operation(
+            "RSA-2048",
+            "Sign",
+            || signatures::sign_rsa(keys, &hashed),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "RSA-2048",
+            "Verify",
+            || signatures::verify_rsa(keys, &hashed, &signature),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("ECDSA P-256") {
+        let signature = signatures::sign_ecdsa(keys, message);
+        results.extend(scale_operation(
+            "ECDSA P-256",
+            "Sign",
+            || signatures::sign_ecdsa(keys, message),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "ECDSA P-256",
+            "Verify",
+            || signatures::verify_ecdsa(keys, message, &signature),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("Dilithium (ML-DSA-44)") {
+        let signature = signatures::sign_dilithium(keys, message);
+        results.extend(scale_operation(
+            "Dilithium (ML-DSA-44)",
+            "Sign",
+            || signatures::sign_dilithium(keys, message),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "Dilithium (ML-DSA-44)",
+            "Verify",
+            || signatures::verify_dilithium(keys, &signature),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("Falcon-512") {
+        let signature = signatures::sign_falcon(keys, message);
+        results.extend(scale_operation(
+            "Falcon-512",
+            "Sign",
+            || signatures::sign_falcon(keys, message),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "Falcon-512",
+            "Verify",
+            || signatures::verify_falcon(keys, &signature),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("X25519 Diffie-Hellman") {
+        let peer = X25519PublicKey::from(&EphemeralSecret::random_from_rng(OsRng));
+        results.extend(scale_operation(
+            "X25519 Diffie-Hellman",
+            "Key Exchange",
+            || kem::exchange_x25519(&peer),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("ECDH (P-256)") {
+        let peer = P256EphemeralSecret::random(&mut OsRng).public_key();
+        results.extend(scale_operation(
+            "ECDH (P-256)",
+            "Key Exchange",
+            || kem::exchange_ecdh(&peer),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    if config.includes("Kyber (ML-KEM-512)") {
+        let (_, ciphertext) = kem::encapsulate_kyber(keys);
+        results.extend(scale_operation(
+            "Kyber (ML-KEM-512)",
+            "Encapsulate",
+            || kem::encapsulate_kyber(keys),
+            max_threads,
+            duration,
+            windows,
+        ));
+        results.extend(scale_operation(
+            "Kyber (ML-KEM-512)",
+            "Decapsulate",
+            || kem::decapsulate_kyber(keys, &ciphertext),
+            max_threads,
+            duration,
+            windows,
+        ));
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_counts() {
+        assert_eq!(thread_counts(1), vec![1]);
+        assert_eq!(thread_counts(4), vec![1, 2, 4]);
+        assert_eq!(thread_counts(6), vec![1, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_scale_operation_reports_each_thread_count() {
+        let results = scale_operation(
+            "Test",
+            "Spin",
+            || (0..100u64).fold(0u64, |acc, x| acc.wrapping_add(black_box(x))),
+            2,
+            Duration::from_millis(20),
+            1,
+        );
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].threads, 1);
+        assert!((results[0].scaling_efficiency - 1.0).abs() < f64::EPSILON);
+        assert!(results.iter().all(|r| r.total_ops > 0));
+    }
+}
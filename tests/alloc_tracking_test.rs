@@ -0,0 +1,16 @@
+use crypto_benchmark::alloc::CountingAllocator;
+use crypto_benchmark::{benchmark_operation_with, MeasurementConfig};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_allocations_are_counted_per_operation() {
+    let config = MeasurementConfig { track_allocations: true, ..Default::default() };
+    let result = benchmark_operation_with(|| vec![0u8; 4096], 10, &config);
+
+    let stats = result.allocations.expect("allocation stats missing");
+    assert_eq!(stats.allocations_per_op, 1.0);
+    assert_eq!(stats.bytes_per_op, 4096.0);
+    assert!(stats.peak_live_bytes >= 4096);
+}
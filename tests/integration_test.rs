@@ -1,4 +1,4 @@
-use crypto_benchmark::{signatures, kem, BenchmarkKeys};
+use crypto_benchmark::{cache, implementations, serialization, signatures, kem, BenchmarkKeys, KeyPool, MeasurementConfig};
 
 #[test]
 fn test_benchmark_keys_generation() {
@@ -20,3 +20,90 @@ fn test_kem_benchmark_runs() {
     kem::benchmark_kem_optimized(&keys);
     // No panic means success
 }
+
+#[test]
+fn test_kem_benchmark_reports_process_resources() {
+    let keys = BenchmarkKeys::generate().expect("Key generation failed");
+    let (metrics, resources) = kem::benchmark_kem_with_resources(&keys, &Default::default());
+    assert_eq!(resources.len(), 3);
+    for usage in &resources {
+        assert!(metrics.iter().any(|m| m.name == usage.algorithm));
+        assert!(usage.rss_peak_bytes >= usage.rss_end_bytes);
+    }
+}
+
+#[test]
+fn test_message_sweep_reports_size_and_rate() {
+    let keys = BenchmarkKeys::generate().expect("Key generation failed");
+    let metrics = signatures::benchmark_message_sweep(&keys, &[32, 1024], &Default::default());
+    // 5 algorithms x (Sign, Verify) x 2 sizes
+    assert_eq!(metrics.len(), 20);
+    for metric in &metrics {
+        let size = metric.message_size.expect("message size missing");
+        assert!(size == 32 || size == 1024);
+        assert!(metric.bytes_per_sec.unwrap_or_default() > 0.0);
+    }
+}
+
+#[test]
+fn test_cache_modes_report_warm_and_cold() {
+    let pool = KeyPool::generate(2).expect("Key pool generation failed");
+    let comparisons = cache::benchmark_cache_modes(&pool, 3, &Default::default());
+    assert_eq!(comparisons.len(), 14);
+    for comparison in &comparisons {
+        assert_eq!(comparison.cold.calls_per_sample, 1);
+        assert_eq!(comparison.cold.extra_info.get("key_sets").map(String::as_str), Some("2"));
+        assert!(comparison.warm.mean_micros > 0.0);
+    }
+}
+
+#[test]
+fn test_verify_key_pool_compares_with_single_key() {
+    let config = MeasurementConfig { iterations: Some(5), ..Default::default() };
+    let pool = KeyPool::generate(3).expect("Key pool generation failed");
+    let comparisons = signatures::benchmark_verify_key_pool(&pool, &config);
+    assert_eq!(comparisons.len(), 5);
+    for comparison in &comparisons {
+        assert_eq!(comparison.pool_size, 3);
+        assert_eq!(comparison.single_key.operation, "Verify");
+        // Kedua sisi diukur dengan closure dan jumlah sample yang sama
+        assert_eq!(comparison.single_key.iterations, comparison.key_pool.iterations);
+        assert!(comparison.single_key.mean_micros > 0.0);
+        assert!(comparison.key_pool.mean_micros > 0.0);
+    }
+}
+
+#[test]
+fn test_serialization_reports_decode_operations() {
+    let keys = BenchmarkKeys::generate().expect("Key generation failed");
+    let metrics = serialization::benchmark_serialization(&keys, &Default::default());
+    for name in ["Ed25519", "RSA-2048", "ECDSA P-256", "Dilithium (ML-DSA-44)", "Falcon-512", "Kyber (ML-KEM-512)"] {
+        assert!(
+            metrics.iter().any(|m| m.name == name && m.operation.starts_with("Decode Public Key")),
+            "missing public key decode for {}",
+            name
+        );
+    }
+    assert!(metrics.iter().all(|m| m.sizes.encoded.is_some()));
+
+    // Setiap artefak yang di-decode juga di-encode, dalam format yang sama
+    for decode in &metrics {
+        if let Some(kind) = decode.operation.strip_prefix("Decode ") {
+            assert!(
+                metrics.iter().any(|m| m.name == decode.name && m.operation.strip_prefix("Encode ") == Some(kind)),
+                "missing encode for {} {}",
+                decode.name,
+                kind
+            );
+        }
+    }
+}
+
+#[test]
+fn test_implementation_dimension_is_reported() {
+    let metrics = implementations::benchmark_implementations(&Default::default());
+    let available = implementations::available_implementations();
+    assert_eq!(metrics.len(), 6 * available.len());
+    assert!(metrics.iter().all(|m| m.implementation.is_some()));
+    assert!(implementations::cross_check(&MeasurementConfig::default()).iter().all(|check| check.passed), "implementations do not interoperate");
+}